@@ -1,5 +1,6 @@
 from torch import load
 
+# Must match `WEIGHT_SCALES` in `src/neural_eval.rs`.
 SCALES = [
     32,
     32,
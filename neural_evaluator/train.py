@@ -70,16 +70,16 @@ class Model(nn.Module):
 
     def forward(self, x):
         x = self.fc1(x)
-        x = F.relu(x)
+        x = torch.clamp(x, 0.0, 1.0)
 
         x = self.fc2(x)
-        x = F.relu(x)
+        x = torch.clamp(x, 0.0, 1.0)
         
         x = self.fc3(x)
-        x = F.relu(x)
+        x = torch.clamp(x, 0.0, 1.0)
         
         x = self.fc4(x)
-        x = F.relu(x)
+        x = torch.clamp(x, 0.0, 1.0)
         
         x = self.fc5(x)
 
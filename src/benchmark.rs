@@ -1,7 +1,16 @@
 use std::time::Instant;
 
+use crate::game::Game;
+use crate::ucicommand::{GoParams, UciCommand};
 use crate::uciprotocol;
 
+pub const BENCH_DEPTH: u8 = 5;
+
+pub struct BenchResult {
+    pub nodes: u64,
+    pub time_ms: u64,
+}
+
 // Standard chess test positions from stockfish's benchmark module
 const POSITIONS: [&str; 41] = [
     "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 10",
@@ -74,3 +83,62 @@ pub fn benchmark(cmds: String) {
 
     println!("TOTAL TIME: {:?}", timer.elapsed());
 }
+
+/// Searches every position to `depth` with a fresh hash table, the
+/// handcrafted evaluation and no book, so the node count only changes when
+/// the search or the evaluation does.
+pub fn bench(depth: u8) -> BenchResult {
+    let mut game = Game::new();
+    game.engine.set_book(false);
+    game.engine.set_nn(false);
+    game.engine.set_print_info(false);
+    let params = GoParams {
+        depth: Some(depth as u64),
+        infinite: true,
+        ..GoParams::default()
+    };
+
+    let timer = Instant::now();
+    let mut nodes = 0;
+    for (i, position) in POSITIONS.iter().enumerate() {
+        eprintln!("Position: {}/{} ({position})", i + 1, POSITIONS.len());
+        let Ok(UciCommand::Position { fen, moves }) =
+            UciCommand::parse(&format!("position fen {position}"))
+        else {
+            unreachable!()
+        };
+        game.new_game();
+        game.set_position(fen.as_ref(), &moves).unwrap();
+        game.analyze(&params);
+        nodes += game.engine.last_info().nodes;
+    }
+
+    BenchResult {
+        nodes,
+        time_ms: timer.elapsed().as_millis() as u64,
+    }
+}
+
+/// Runs `bench` and prints the node count signature.
+pub fn print_bench(depth: u8) {
+    let result = bench(depth);
+    println!("Total time (ms) : {}", result.time_ms);
+    println!("Nodes searched  : {}", result.nodes);
+    println!(
+        "Nodes/second    : {}",
+        result.nodes * 1000 / (result.time_ms + 1)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bench_is_deterministic() {
+        let a = bench(3);
+        let b = bench(3);
+        assert!(a.nodes > 0);
+        assert_eq!(a.nodes, b.nodes);
+    }
+}
@@ -0,0 +1,402 @@
+// Builds an opening book from PGN games.
+//
+// Every move played in the first `--max-ply` plies of a game is counted,
+// together with the result of the game. Moves played in fewer than
+// `--min-games` games, or that scored less than `--min-score` for the side
+// that played them, are left out of the book.
+//
+// The book is written as JSON, a map from shakmaty Zobrist hashes to pairs of
+// a UCI move and the number of games it was played in, or as a Polyglot book
+// when the output file name ends in `.bin` or with `--format polyglot`.
+// Polyglot weights are the number of games as well.
+//
+// Usage:
+//     build_book <pgn>... [--output PATH] [--format json|polyglot]
+//                [--max-ply N] [--min-games N] [--min-score X]
+
+use minikalle::pgn::{Game, PgnError, PgnReader};
+use minikalle::polyglot::{self, Entry};
+use shakmaty::zobrist::{Zobrist64, ZobristHash};
+use shakmaty::{CastlingMode, EnPassantMode, Outcome, Position};
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fs::File;
+use std::io::{BufRead, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::exit;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Json,
+    Polyglot,
+}
+
+struct Config {
+    inputs: Vec<PathBuf>,
+    output: PathBuf,
+    format: Format,
+    max_ply: usize,
+    min_games: u32,
+    min_score: f64,
+}
+
+impl Config {
+    fn from_args() -> Config {
+        let mut args = env::args().skip(1);
+
+        let mut config = Config {
+            inputs: Vec::new(),
+            output: PathBuf::from("book.json"),
+            format: Format::Json,
+            max_ply: 20,
+            min_games: 5,
+            min_score: 0.0,
+        };
+
+        let mut format = None;
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .unwrap_or_else(|| usage(&format!("missing value for {name}")))
+            };
+            match arg.as_str() {
+                "--output" => config.output = PathBuf::from(value(&arg)),
+                "--format" => {
+                    format = match value(&arg).as_str() {
+                        "json" => Some(Format::Json),
+                        "polyglot" => Some(Format::Polyglot),
+                        x => usage(&format!("unknown format {x}")),
+                    }
+                }
+                "--max-ply" => config.max_ply = parse(&value(&arg), &arg),
+                "--min-games" => config.min_games = parse(&value(&arg), &arg),
+                "--min-score" => config.min_score = parse(&value(&arg), &arg),
+                x if x.starts_with("--") => usage(&format!("unknown flag {x}")),
+                _ => config.inputs.push(PathBuf::from(arg)),
+            }
+        }
+
+        config.format = format.unwrap_or_else(|| {
+            if config.output.extension().is_some_and(|ext| ext == "bin") {
+                Format::Polyglot
+            } else {
+                Format::Json
+            }
+        });
+
+        if config.inputs.is_empty() {
+            usage("missing pgn file");
+        }
+        if !(0.0..=1.0).contains(&config.min_score) {
+            usage("minimum score must be between 0 and 1");
+        }
+
+        config
+    }
+}
+
+fn parse<T: std::str::FromStr>(value: &str, name: &str) -> T {
+    value
+        .parse()
+        .unwrap_or_else(|_| usage(&format!("invalid value {value} for {name}")))
+}
+
+fn usage(message: &str) -> ! {
+    eprintln!("{message}");
+    eprintln!(
+        "usage: build_book <pgn>... [--output PATH] [--format json|polyglot] [--max-ply N] \
+         [--min-games N] [--min-score X]"
+    );
+    exit(1);
+}
+
+/// Results of the games a move was played in, from the point of view of the
+/// side that played it.
+#[derive(Default)]
+struct MoveStats {
+    /// Polyglot encoding of the move.
+    raw_move: u16,
+    wins: u32,
+    draws: u32,
+    losses: u32,
+}
+
+impl MoveStats {
+    fn games(&self) -> u32 {
+        self.wins + self.draws + self.losses
+    }
+
+    fn score(&self) -> f64 {
+        (self.wins as f64 + 0.5 * self.draws as f64) / self.games() as f64
+    }
+
+    fn accepted(&self, config: &Filter) -> bool {
+        self.games() >= config.min_games && self.score() >= config.min_score
+    }
+}
+
+/// Which moves are written to the book.
+struct Filter {
+    min_games: u32,
+    min_score: f64,
+}
+
+struct PositionStats {
+    polyglot_key: u64,
+    /// Keyed by the move in UCI notation.
+    moves: HashMap<String, MoveStats>,
+}
+
+/// Counts the moves played in the first `max_ply` plies of every game into
+/// `positions`. Returns the number of games read and the number skipped
+/// because they have no result.
+fn count_moves<R: BufRead>(
+    pgn: PgnReader<R>,
+    name: &str,
+    max_ply: usize,
+    positions: &mut HashMap<u64, PositionStats>,
+) -> (u32, u32) {
+    let mut games = 0;
+    let mut skipped = 0;
+
+    for game in pgn {
+        let game: Game = match game {
+            Ok(game) => game,
+            Err(err @ PgnError::Io(_)) => {
+                eprintln!("{name}: {err}");
+                break;
+            }
+            Err(err) => {
+                eprintln!("{name}: {err}");
+                continue;
+            }
+        };
+
+        let (Some(outcome), Some(mut position)) = (game.result, game.starting_position()) else {
+            skipped += 1;
+            continue;
+        };
+        games += 1;
+
+        for san in game.moves.iter().take(max_ply) {
+            let Ok(m) = san.to_move(&position) else {
+                eprintln!("{name}: illegal move {san}");
+                break;
+            };
+
+            let zobrist = position.zobrist_hash::<Zobrist64>(EnPassantMode::Legal);
+            let stats = positions.entry(zobrist.0).or_insert_with(|| PositionStats {
+                polyglot_key: polyglot::key(&position),
+                moves: HashMap::new(),
+            });
+            let uci = m.to_uci(CastlingMode::Standard).to_string();
+            let move_stats = stats.moves.entry(uci).or_insert_with(|| MoveStats {
+                raw_move: polyglot::encode_move(&m),
+                ..MoveStats::default()
+            });
+            match outcome {
+                Outcome::Draw => move_stats.draws += 1,
+                Outcome::Decisive { winner } if winner == position.turn() => move_stats.wins += 1,
+                Outcome::Decisive { .. } => move_stats.losses += 1,
+            }
+
+            position.play_unchecked(&m);
+        }
+    }
+
+    (games, skipped)
+}
+
+/// The accepted moves of every position with the number of games they were
+/// played in, most played first.
+fn json_book<'a>(
+    positions: &'a HashMap<u64, PositionStats>,
+    filter: &Filter,
+) -> BTreeMap<u64, Vec<(&'a str, u32)>> {
+    positions
+        .iter()
+        .map(|(zobrist, stats)| {
+            let mut moves: Vec<(&str, u32)> = stats
+                .moves
+                .iter()
+                .filter(|(_, s)| s.accepted(filter))
+                .map(|(uci, s)| (uci.as_str(), s.games()))
+                .collect();
+            moves.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+            (*zobrist, moves)
+        })
+        .filter(|(_, moves)| !moves.is_empty())
+        .collect()
+}
+
+/// The accepted moves as Polyglot entries, sorted by key.
+fn polyglot_entries(positions: &HashMap<u64, PositionStats>, filter: &Filter) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = positions
+        .values()
+        .flat_map(|stats| {
+            stats
+                .moves
+                .values()
+                .filter(|s| s.accepted(filter))
+                .map(|s| Entry {
+                    key: stats.polyglot_key,
+                    raw_move: s.raw_move,
+                    weight: s.games().min(u16::MAX as u32) as u16,
+                    learn: 0,
+                })
+        })
+        .collect();
+    entries.sort_by(|a, b| a.key.cmp(&b.key).then(b.weight.cmp(&a.weight)));
+    entries
+}
+
+fn main() {
+    let config = Config::from_args();
+
+    let mut positions: HashMap<u64, PositionStats> = HashMap::new();
+    let mut games = 0;
+    let mut skipped = 0;
+
+    for path in &config.inputs {
+        let pgn = PgnReader::open(path).unwrap_or_else(|err| {
+            eprintln!("{}: {err}", path.display());
+            exit(1);
+        });
+        let counts = count_moves(
+            pgn,
+            &path.display().to_string(),
+            config.max_ply,
+            &mut positions,
+        );
+        games += counts.0;
+        skipped += counts.1;
+    }
+
+    let filter = Filter {
+        min_games: config.min_games,
+        min_score: config.min_score,
+    };
+
+    let file = File::create(&config.output).unwrap_or_else(|err| {
+        eprintln!("{}: {err}", config.output.display());
+        exit(1);
+    });
+    let mut writer = BufWriter::new(file);
+
+    let n_positions = match config.format {
+        Format::Json => {
+            let book = json_book(&positions, &filter);
+            serde_json::to_writer(&mut writer, &book).expect("cannot write book");
+            book.len()
+        }
+        Format::Polyglot => {
+            let mut entries = polyglot_entries(&positions, &filter);
+            for entry in &entries {
+                writer
+                    .write_all(&entry.to_bytes())
+                    .expect("cannot write book");
+            }
+            entries.dedup_by_key(|e| e.key);
+            entries.len()
+        }
+    };
+    writer.flush().expect("cannot write book");
+
+    eprintln!("read {games} games, skipped {skipped} without a result");
+    eprintln!(
+        "wrote {n_positions} positions to {}",
+        config.output.display()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shakmaty::Chess;
+
+    const PGN: &str = r#"[Result "1-0"]
+
+1. e4 e5 2. Nf3 1-0
+
+[Result "1/2-1/2"]
+
+1. e4 c5 1/2-1/2
+
+[Result "0-1"]
+
+1. d4 d5 0-1
+
+[Result "*"]
+
+1. c4 *
+"#;
+
+    fn count(max_ply: usize) -> HashMap<u64, PositionStats> {
+        let mut positions = HashMap::new();
+        let counts = count_moves(
+            PgnReader::new(PGN.as_bytes()),
+            "test",
+            max_ply,
+            &mut positions,
+        );
+        assert_eq!(counts, (3, 1));
+        positions
+    }
+
+    fn start_hash() -> u64 {
+        Chess::default()
+            .zobrist_hash::<Zobrist64>(EnPassantMode::Legal)
+            .0
+    }
+
+    #[test]
+    fn test_aggregation() {
+        let positions = count(2);
+        // The start position and the positions after 1. e4 and 1. d4.
+        assert_eq!(positions.len(), 3);
+
+        let stats = &positions[&start_hash()].moves;
+        assert_eq!(stats["e2e4"].wins, 1);
+        assert_eq!(stats["e2e4"].draws, 1);
+        assert_eq!(stats["d2d4"].losses, 1);
+
+        let filter = Filter {
+            min_games: 1,
+            min_score: 0.0,
+        };
+        let book = json_book(&positions, &filter);
+        assert_eq!(book[&start_hash()], vec![("e2e4", 2), ("d2d4", 1)]);
+        assert_eq!(
+            serde_json::to_string(&book[&start_hash()]).unwrap(),
+            r#"[["e2e4",2],["d2d4",1]]"#
+        );
+
+        let entries = polyglot_entries(&positions, &filter);
+        assert_eq!(entries.len(), 5);
+        assert!(entries.windows(2).all(|w| w[0].key <= w[1].key));
+    }
+
+    #[test]
+    fn test_filters() {
+        let positions = count(20);
+
+        let filter = Filter {
+            min_games: 2,
+            min_score: 0.0,
+        };
+        let book = json_book(&positions, &filter);
+        assert_eq!(book.len(), 1);
+        assert_eq!(book[&start_hash()], vec![("e2e4", 2)]);
+
+        // 1. e4 scored 0.75 and 1. d4 scored 0, of the replies only 1... e5 scored
+        // less than 0.5.
+        let filter = Filter {
+            min_games: 1,
+            min_score: 0.5,
+        };
+        let book = json_book(&positions, &filter);
+        assert_eq!(book[&start_hash()], vec![("e2e4", 2)]);
+        assert_eq!(book.values().map(Vec::len).sum::<usize>(), 4);
+    }
+}
@@ -0,0 +1,109 @@
+// Upgrades CSV training data written by older versions of `generate_data`
+// to the binary format in `trainingdata.rs`.
+//
+// Each CSV line is the game result followed by the 768 network inputs. The
+// CSV format does not store the side to move, so converted positions are
+// marked as white to move.
+//
+// Usage:
+//     convert <input.csv> <output>
+
+use minikalle::trainingdata::{Record, RecordWriter, FEATURES};
+use shakmaty::{Bitboard, Board, ByColor, ByRole, Color, Role};
+use std::env;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter};
+use std::process::exit;
+
+const ROLES: [Role; 6] = [
+    Role::Pawn,
+    Role::Knight,
+    Role::Bishop,
+    Role::Rook,
+    Role::Queen,
+    Role::King,
+];
+
+fn parse_line(line: &str) -> Result<Record, String> {
+    let mut values = line.trim().split(',');
+
+    let result: i8 = values
+        .next()
+        .and_then(|x| x.parse().ok())
+        .filter(|x| (-1..=1).contains(x))
+        .ok_or("invalid game result")?;
+
+    let mut by_role = ByRole::new_with(|_| Bitboard::EMPTY);
+    let mut by_color = ByColor::new_with(|_| Bitboard::EMPTY);
+
+    let mut n = 0;
+    for (i, value) in values.enumerate() {
+        match value {
+            "0" => (),
+            "1" if i < FEATURES => {
+                let color = if i / 384 == 0 {
+                    Color::White
+                } else {
+                    Color::Black
+                };
+                let role = ROLES[i / 64 % 6];
+                let sq = Bitboard(1 << (i % 64));
+
+                if !(by_color.white | by_color.black).is_disjoint(sq) {
+                    return Err(format!("two pieces on square {}", i % 64));
+                }
+                *by_color.get_mut(color) |= sq;
+                *by_role.get_mut(role) |= sq;
+            }
+            x => return Err(format!("invalid input {x}")),
+        }
+        n += 1;
+    }
+
+    if n != FEATURES {
+        return Err(format!("expected {FEATURES} inputs, got {n}"));
+    }
+
+    Ok(Record {
+        board: Board::from_bitboards(by_role, by_color),
+        turn: Color::White,
+        result,
+        score: None,
+    })
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        eprintln!("usage: convert <input.csv> <output>");
+        exit(1);
+    }
+
+    let input = File::open(&args[1]).expect("cannot open input file");
+    let output = File::create(&args[2]).expect("cannot create output file");
+
+    let mut writer = RecordWriter::new(BufWriter::new(output)).expect("cannot write output");
+
+    let mut converted = 0;
+    let mut skipped = 0;
+
+    for (i, line) in BufReader::new(input).lines().enumerate() {
+        let line = line.expect("cannot read input file");
+        if line.trim().is_empty() {
+            continue;
+        }
+        match parse_line(&line) {
+            Ok(record) => {
+                writer.write(&record).expect("cannot write output");
+                converted += 1;
+            }
+            Err(err) => {
+                eprintln!("line {}: {err}", i + 1);
+                skipped += 1;
+            }
+        }
+    }
+
+    writer.flush().expect("cannot write output");
+    eprintln!("converted {converted} positions, skipped {skipped}");
+}
@@ -1,194 +1,784 @@
-use crate::pgn::PgnIterator;
-use shakmaty::{san::San, Chess, Position};
-use std::{env, io::stdin};
+// Generates network training data from PGN games or self-play, in the binary
+// format described in `src/trainingdata.rs`.
+//
+// Usage:
+//     generate_data [<pgn>...] [--input PGN] [--output PATH] [--shard-size N]
+//                   [--max-positions N] [--sample X] [--threads N]
+//                   [--depth N] [--nodes N] [--interactive]
+//                   [--skip-plies N] [--skip-checks] [--skip-noisy]
+//                   [--min-elo N] [--max-elo N] [--dedup] [--dedup-mb N]
+//     generate_data --selfplay [--games N] [--openings FILE] [--random-plies N]
+//                   [--output PATH] [--shard-size N] [--max-positions N]
+//                   [--threads N] [--depth N] [--nodes N]
+//
+// Games are read from the PGN files in order and split across `--threads`
+// workers. Without `--output` the records are written to stdout. With
+// `--shard-size N` the output path is a directory that is filled with files of
+// at most N records each, which `train` reads in name order.
+
+use minikalle::pgn::{Game, PgnReader};
+use minikalle::search::{Engine, Limits};
+use minikalle::trainingdata::{Record, RecordWriter};
+use rand::prelude::*;
+use shakmaty::zobrist::{Zobrist64, ZobristHash};
+use shakmaty::{Chess, Color, EnPassantMode, Move, Outcome, Position};
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, stdin, stdout, BufWriter, Write};
+use std::path::PathBuf;
+use std::process::exit;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// Default node budget per move in self-play.
+const SELFPLAY_NODES: u64 = 10_000;
+// Default size of the deduplication bloom filter in megabytes.
+const DEDUP_MEGABYTES: usize = 256;
+// Games buffered per worker between the PGN reader and the workers.
+const GAMES_PER_WORKER: usize = 16;
+
+struct Config {
+    inputs: Vec<PathBuf>,
+    output: Option<PathBuf>,
+    shard_size: Option<usize>,
+    max_positions: usize,
+    threads: usize,
+    interactive: bool,
+    depth: Option<u8>,
+    nodes: Option<u64>,
+    filters: Filters,
+    selfplay: bool,
+    games: usize,
+    openings: Option<String>,
+    random_plies: usize,
+}
+
+impl Config {
+    fn from_args() -> Config {
+        let mut args = env::args().skip(1);
+
+        let mut config = Config {
+            inputs: Vec::new(),
+            output: None,
+            shard_size: None,
+            max_positions: usize::MAX,
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+            interactive: false,
+            depth: None,
+            nodes: None,
+            filters: Filters::default(),
+            selfplay: false,
+            games: 1_000,
+            openings: None,
+            random_plies: 8,
+        };
+
+        let mut dedup = false;
+        let mut dedup_megabytes = DEDUP_MEGABYTES;
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .unwrap_or_else(|| usage(&format!("missing value for {name}")))
+            };
+            match arg.as_str() {
+                "--input" => config.inputs.push(PathBuf::from(value(&arg))),
+                "--output" => config.output = Some(PathBuf::from(value(&arg))),
+                "--shard-size" => config.shard_size = Some(parse(&value(&arg), &arg)),
+                "--max-positions" => config.max_positions = parse(&value(&arg), &arg),
+                "--sample" => config.filters.sample = parse(&value(&arg), &arg),
+                "--threads" => config.threads = parse(&value(&arg), &arg),
+                "--interactive" => config.interactive = true,
+                "--depth" => config.depth = Some(parse(&value(&arg), &arg)),
+                "--nodes" => config.nodes = Some(parse(&value(&arg), &arg)),
+                "--skip-plies" => config.filters.skip_plies = parse(&value(&arg), &arg),
+                "--skip-checks" => config.filters.skip_checks = true,
+                "--skip-noisy" => config.filters.skip_noisy = true,
+                "--min-elo" => config.filters.min_elo = Some(parse(&value(&arg), &arg)),
+                "--max-elo" => config.filters.max_elo = Some(parse(&value(&arg), &arg)),
+                "--dedup" => dedup = true,
+                "--dedup-mb" => dedup_megabytes = parse(&value(&arg), &arg),
+                "--selfplay" => config.selfplay = true,
+                "--games" => config.games = parse(&value(&arg), &arg),
+                "--openings" => config.openings = Some(value(&arg)),
+                "--random-plies" => config.random_plies = parse(&value(&arg), &arg),
+                x if x.starts_with("--") => usage(&format!("unknown flag {x}")),
+                _ => config.inputs.push(PathBuf::from(arg)),
+            }
+        }
+
+        if dedup {
+            config.filters.dedup = Some(BloomFilter::new(dedup_megabytes));
+        }
+
+        if !config.selfplay && config.inputs.is_empty() {
+            usage("missing pgn file");
+        }
+        if config.threads == 0 {
+            usage("thread count must be positive");
+        }
+        if config.shard_size == Some(0) {
+            usage("shard size must be positive");
+        }
+        if config.shard_size.is_some() && config.output.is_none() {
+            usage("--shard-size requires --output");
+        }
+        if !(config.filters.sample > 0.0 && config.filters.sample <= 1.0) {
+            usage("sample rate must be in (0, 1]");
+        }
+        if config.interactive && config.threads > 1 {
+            eprintln!("--interactive uses a single thread");
+            config.threads = 1;
+        }
+
+        config
+    }
+}
 
-mod pgn {
-    use std::collections::HashMap;
-    use std::fs::File;
-    use std::io::{BufReader, Read};
+fn parse<T: std::str::FromStr>(value: &str, name: &str) -> T {
+    value
+        .parse()
+        .unwrap_or_else(|_| usage(&format!("invalid value {value} for {name}")))
+}
+
+fn usage(message: &str) -> ! {
+    eprintln!("{message}");
+    eprintln!(
+        "usage: generate_data [<pgn>...] [--input PGN] [--output PATH] [--shard-size N] \
+         [--max-positions N] [--sample X] [--threads N] [--depth N] [--nodes N] \
+         [--interactive] [--skip-plies N] [--skip-checks] [--skip-noisy] [--min-elo N] \
+         [--max-elo N] [--dedup] [--dedup-mb N]"
+    );
+    eprintln!(
+        "       generate_data --selfplay [--games N] [--openings FILE] [--random-plies N] \
+         [--output PATH] [--shard-size N] [--max-positions N] [--threads N] [--depth N] \
+         [--nodes N]"
+    );
+    exit(1);
+}
+
+/// Labels positions with a fixed depth or node count search.
+struct Scorer {
+    engine: Engine,
+    limits: Limits,
+}
 
-    #[derive(Debug)]
-    pub struct Game {
-        pub moves: Vec<String>,
-        pub headers: HashMap<String, String>,
+impl Scorer {
+    fn new(depth: Option<u8>, nodes: Option<u64>) -> Scorer {
+        let mut engine = Engine::new();
+        engine.set_book(false);
+        engine.set_print_info(false);
+        engine.set_hash(16).expect("cannot allocate hash table");
+
+        Scorer {
+            engine,
+            limits: Limits {
+                depth,
+                nodes,
+                ..Limits::default()
+            },
+        }
     }
 
-    pub struct PgnIterator {
-        pgn: BufReader<File>,
+    /// Best move and search score from white's point of view.
+    fn search(&mut self, position: &Chess) -> (Move, i16) {
+        let result = self.engine.search(position, &self.limits);
+        let score = match position.turn() {
+            Color::White => result.score,
+            Color::Black => -result.score,
+        };
+        (result.best_move, score)
     }
 
-    impl PgnIterator {
-        pub fn new(path: String) -> Self {
-            let file = File::open(path).expect("cannot open pgn file");
-            let pgn = BufReader::new(file);
+    fn score(&mut self, position: &Chess) -> i16 {
+        self.search(position).1
+    }
+}
+
+/// Approximate set of Zobrist hashes, used to drop repeated positions.
+/// May report a position as seen that was not, but never the other way around.
+struct BloomFilter {
+    bits: Vec<AtomicU64>,
+}
+
+impl BloomFilter {
+    const HASHES: u64 = 4;
+
+    fn new(megabytes: usize) -> BloomFilter {
+        BloomFilter {
+            bits: (0..(megabytes.max(1) << 20) / 8)
+                .map(|_| AtomicU64::new(0))
+                .collect(),
+        }
+    }
 
-            PgnIterator { pgn }
+    /// Adds `hash` and returns whether it was already present.
+    fn insert(&self, hash: u64) -> bool {
+        let n_bits = self.bits.len() as u64 * 64;
+        // Double hashing, the second hash is odd so that the probes differ.
+        let step = hash.rotate_left(32) | 1;
+
+        let mut present = true;
+        for i in 0..Self::HASHES {
+            let bit = hash.wrapping_add(i.wrapping_mul(step)) % n_bits;
+            let (word, mask) = ((bit / 64) as usize, 1 << (bit % 64));
+            present &= self.bits[word].fetch_or(mask, Ordering::Relaxed) & mask != 0;
         }
+        present
     }
+}
 
-    impl Iterator for PgnIterator {
-        type Item = Game;
+/// Which positions of a PGN game are written to the output.
+struct Filters {
+    skip_plies: usize,
+    skip_checks: bool,
+    skip_noisy: bool,
+    min_elo: Option<u16>,
+    max_elo: Option<u16>,
+    /// Probability of keeping a position that passes the other filters.
+    sample: f64,
+    dedup: Option<BloomFilter>,
+}
 
-        fn next(&mut self) -> Option<Self::Item> {
-            // This is a shit praser but it is faster than python.
+impl Default for Filters {
+    fn default() -> Filters {
+        Filters {
+            skip_plies: 0,
+            skip_checks: false,
+            skip_noisy: false,
+            min_elo: None,
+            max_elo: None,
+            sample: 1.0,
+            dedup: None,
+        }
+    }
+}
 
-            let mut game = Game {
-                moves: vec![],
-                headers: HashMap::new(),
+impl Filters {
+    fn accepts_game(&self, game: &Game) -> bool {
+        let elos = [game.headers.white_elo(), game.headers.black_elo()];
+        elos.iter().all(|elo| {
+            self.min_elo
+                .is_none_or(|min| elo.is_some_and(|elo| elo >= min))
+                && self
+                    .max_elo
+                    .is_none_or(|max| elo.is_some_and(|elo| elo <= max))
+        })
+    }
+
+    /// Checks the cheap filters first so that the quiescence search and the
+    /// bloom filter only see positions that pass everything else.
+    fn accepts_position(
+        &self,
+        position: &Chess,
+        ply: usize,
+        quiet: &Engine,
+        rng: &mut impl Rng,
+        summary: &mut Summary,
+    ) -> bool {
+        let counter = if ply < self.skip_plies {
+            &mut summary.opening
+        } else if !position.capture_moves().is_empty() {
+            &mut summary.captures
+        } else if self.skip_checks && position.is_check() {
+            &mut summary.checks
+        } else if !rng.gen_bool(self.sample) {
+            &mut summary.sampling
+        } else if self.skip_noisy && !quiet.is_quiet(position) {
+            &mut summary.noisy
+        } else if self.dedup.as_ref().is_some_and(|dedup| {
+            dedup.insert(position.zobrist_hash::<Zobrist64>(EnPassantMode::Legal).0)
+        }) {
+            &mut summary.duplicates
+        } else {
+            return true;
+        };
+        *counter += 1;
+        false
+    }
+}
+
+/// Number of games and positions dropped by each filter.
+#[derive(Default)]
+struct Summary {
+    games: usize,
+    games_elo: usize,
+    games_unfinished: usize,
+    written: usize,
+    opening: usize,
+    captures: usize,
+    checks: usize,
+    sampling: usize,
+    noisy: usize,
+    duplicates: usize,
+}
+
+impl Summary {
+    fn add(&mut self, other: &Summary) {
+        self.games += other.games;
+        self.games_elo += other.games_elo;
+        self.games_unfinished += other.games_unfinished;
+        self.written += other.written;
+        self.opening += other.opening;
+        self.captures += other.captures;
+        self.checks += other.checks;
+        self.sampling += other.sampling;
+        self.noisy += other.noisy;
+        self.duplicates += other.duplicates;
+    }
+
+    fn print(&self) {
+        eprintln!("games read:                  {}", self.games);
+        eprintln!("  skipped, elo:              {}", self.games_elo);
+        eprintln!("  skipped, unfinished:       {}", self.games_unfinished);
+        eprintln!("positions written:           {}", self.written);
+        eprintln!("  dropped, opening plies:    {}", self.opening);
+        eprintln!("  dropped, captures:         {}", self.captures);
+        eprintln!("  dropped, in check:         {}", self.checks);
+        eprintln!("  dropped, sampling:         {}", self.sampling);
+        eprintln!("  dropped, not quiet:        {}", self.noisy);
+        eprintln!("  dropped, duplicates:       {}", self.duplicates);
+    }
+}
+
+/// Writes records to stdout, to a single file, or to numbered shards of
+/// `shard_size` records in a directory.
+struct Output {
+    path: Option<PathBuf>,
+    shard_size: Option<usize>,
+    shards: usize,
+    in_shard: usize,
+    writer: Option<RecordWriter<BufWriter<Box<dyn Write>>>>,
+}
+
+impl Output {
+    fn new(path: Option<PathBuf>, shard_size: Option<usize>) -> io::Result<Output> {
+        if let (Some(path), Some(_)) = (&path, shard_size) {
+            fs::create_dir_all(path)?;
+        }
+        Ok(Output {
+            path,
+            shard_size,
+            shards: 0,
+            in_shard: 0,
+            writer: None,
+        })
+    }
+
+    fn write(&mut self, record: &Record) -> io::Result<()> {
+        if self
+            .shard_size
+            .is_some_and(|shard_size| self.in_shard >= shard_size)
+        {
+            self.flush()?;
+            self.writer = None;
+        }
+
+        let writer = match &mut self.writer {
+            Some(writer) => writer,
+            None => {
+                let inner: Box<dyn Write> = match (&self.path, self.shard_size) {
+                    (Some(path), Some(_)) => {
+                        Box::new(File::create(path.join(format!("{:05}.bin", self.shards)))?)
+                    }
+                    (Some(path), None) => Box::new(File::create(path)?),
+                    (None, _) => Box::new(stdout().lock()),
+                };
+                self.shards += 1;
+                self.in_shard = 0;
+                self.writer
+                    .insert(RecordWriter::new(BufWriter::new(inner))?)
+            }
+        };
+
+        writer.write(record)?;
+        self.in_shard += 1;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.writer {
+            Some(writer) => writer.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Positions of one game that passed the filters, and what was dropped.
+struct Batch {
+    records: Vec<Record>,
+    summary: Summary,
+}
+
+/// Turns the games of a PGN file into training records.
+struct Worker {
+    config: Arc<Config>,
+    scorer: Option<Scorer>,
+    /// Used by the quiescence filter.
+    quiet: Engine,
+    rng: ThreadRng,
+}
+
+impl Worker {
+    fn new(config: Arc<Config>) -> Worker {
+        let scorer = if config.depth.is_some() || config.nodes.is_some() {
+            Some(Scorer::new(config.depth, config.nodes))
+        } else {
+            None
+        };
+
+        let mut quiet = Engine::new();
+        quiet.set_hash(1).expect("cannot allocate hash table");
+        quiet.set_print_info(false);
+
+        Worker {
+            config,
+            scorer,
+            quiet,
+            rng: thread_rng(),
+        }
+    }
+
+    fn run(mut self, games: &Mutex<Receiver<(usize, Game)>>, batches: Sender<Batch>) {
+        loop {
+            let Ok((input, game)) = games.lock().unwrap().recv() else {
+                return;
             };
+            if batches.send(self.process(input, &game)).is_err() {
+                return;
+            }
+        }
+    }
 
-            let mut is_game = false;
-            let mut read_key = true;
-            let mut prev_newline = 0;
-            let mut key = String::new();
-            let mut value = String::new();
-            let mut move_buf = String::new();
-
-            let mut buf = [0; 1];
-            let mut i = 0;
-
-            while self.pgn.read(&mut buf).is_ok() {
-                let c = buf[0] as char;
-
-                // header parsing
-                if !is_game {
-                    match c {
-                        '[' => read_key = true,
-                        ' ' => read_key = false,
-                        ']' => {
-                            game.headers.insert(key.clone(), value.clone());
-                            key.clear();
-                            value.clear();
-                        }
-                        '\n' => {
-                            if i - 1 == prev_newline {
-                                is_game = true;
-                            }
-                            prev_newline = i;
-                        }
-                        _ => {
-                            if read_key {
-                                key.push(c)
-                            } else {
-                                value.push(c)
-                            }
-                        }
+    fn process(&mut self, input: usize, game: &Game) -> Batch {
+        let filters = &self.config.filters;
+        let mut records = Vec::new();
+        let mut summary = Summary {
+            games: 1,
+            ..Summary::default()
+        };
+
+        if !filters.accepts_game(game) {
+            summary.games_elo += 1;
+            return Batch { records, summary };
+        }
+
+        let y = match game.result {
+            _ if game.headers.termination() != Some("Normal") => None,
+            Some(Outcome::Decisive { winner }) => Some(winner.fold_wb(1, -1)),
+            Some(Outcome::Draw) => Some(0),
+            None => None,
+        };
+        let Some(y) = y else {
+            summary.games_unfinished += 1;
+            return Batch { records, summary };
+        };
+
+        let Some(mut board) = game.starting_position() else {
+            return Batch { records, summary };
+        };
+
+        for (ply, san) in game.moves.iter().enumerate() {
+            let Ok(m) = san.to_move(&board) else {
+                eprintln!(
+                    "{}: illegal move {san}",
+                    self.config.inputs[input].display()
+                );
+                break;
+            };
+            board.play_unchecked(&m);
+
+            if !filters.accepts_position(&board, ply + 1, &self.quiet, &mut self.rng, &mut summary)
+            {
+                continue;
+            }
+
+            let score = self.scorer.as_mut().map(|s| s.score(&board));
+            records.push(Record::new(&board, y, score));
+        }
+
+        Batch { records, summary }
+    }
+}
+
+/// Reads the PGN files on one thread, processes the games on
+/// `config.threads` workers and passes the results to `output`.
+fn run_pgn(config: Config, mut output: impl FnMut(Batch) -> bool) {
+    let config = Arc::new(config);
+    let (game_sender, game_receiver) = mpsc::sync_channel(config.threads * GAMES_PER_WORKER);
+    let game_receiver = Arc::new(Mutex::new(game_receiver));
+    let (batch_sender, batch_receiver) = mpsc::channel();
+
+    let reader = {
+        let config = Arc::clone(&config);
+        thread::spawn(move || {
+            for (input, path) in config.inputs.iter().enumerate() {
+                let pgn = match PgnReader::open(path) {
+                    Ok(pgn) => pgn,
+                    Err(err) => {
+                        eprintln!("{}: {err}", path.display());
+                        continue;
                     }
-                } else {
-                    match c {
-                        '?' => (),
-                        '!' => (),
-                        ' ' => {
-                            game.moves.push(move_buf.clone());
-                            move_buf.clear();
-                        }
-                        '\n' => {
-                            if i - 1 == prev_newline {
-                                return Some(game);
+                };
+                for game in pgn {
+                    match game {
+                        Ok(game) => {
+                            if game_sender.send((input, game)).is_err() {
+                                return;
                             }
-                            prev_newline = i;
                         }
-                        _ => move_buf.push(c),
+                        Err(err) => eprintln!("{}: {err}", path.display()),
                     }
                 }
-                i += 1;
             }
+        })
+    };
 
-            return None;
+    let workers: Vec<_> = (0..config.threads)
+        .map(|_| {
+            let config = Arc::clone(&config);
+            let game_receiver = Arc::clone(&game_receiver);
+            let batch_sender = batch_sender.clone();
+            thread::spawn(move || Worker::new(config).run(&game_receiver, batch_sender))
+        })
+        .collect();
+    drop(game_receiver);
+    drop(batch_sender);
+
+    for batch in batch_receiver.iter() {
+        if !output(batch) {
+            break;
         }
     }
+    // Workers stop when they cannot send their next batch, and the reader
+    // when no worker is left to receive games.
+    drop(batch_receiver);
+
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    reader.join().unwrap();
 }
 
-fn serialize(position: &Chess) -> [i32; 768] {
-    let board = position.board();
+mod selfplay {
+    use crate::Scorer;
+    use minikalle::trainingdata::Record;
+    use rand::prelude::*;
+    use shakmaty::fen::Fen;
+    use shakmaty::zobrist::{Zobrist64, ZobristHash};
+    use shakmaty::{CastlingMode, Chess, EnPassantMode, Outcome, Position};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::mpsc::{self, Sender};
+    use std::sync::Arc;
+    use std::thread;
+
+    // Adjudicate a win when the score stays above this for `WIN_PLIES` plies.
+    const WIN_SCORE: i16 = 1_000;
+    const WIN_PLIES: usize = 4;
+    // Adjudicate a draw when the score stays within this for `DRAW_PLIES`
+    // plies, after `DRAW_MIN_PLY` plies have been played.
+    const DRAW_SCORE: i16 = 10;
+    const DRAW_PLIES: usize = 12;
+    const DRAW_MIN_PLY: usize = 80;
+    const MAX_PLIES: usize = 400;
+    // Random openings tried for a game before giving up, when they all end the
+    // game before it starts.
+    const MAX_OPENING_TRIES: usize = 1_000;
+
+    pub struct Config {
+        pub games: usize,
+        pub threads: usize,
+        pub depth: Option<u8>,
+        pub nodes: u64,
+        /// Starting positions, the standard starting position is used when empty.
+        pub openings: Vec<Chess>,
+        /// Number of random moves played from the opening position.
+        pub random_plies: usize,
+    }
 
-    let mut result = [0; 768];
-    let mut index = 0;
+    pub fn load_openings(path: &str) -> Vec<Chess> {
+        let text = std::fs::read_to_string(path).expect("cannot read openings file");
 
-    let white = board.white();
-    let black = board.black();
+        text.lines()
+            .filter_map(|line| line.parse::<Fen>().ok())
+            .filter_map(|fen| fen.into_position(CastlingMode::Standard).ok())
+            .collect()
+    }
+
+    /// Plays the games on `config.threads` threads and passes the positions of
+    /// every finished game to `output`.
+    pub fn run(config: Config, mut output: impl FnMut(Vec<Record>) -> bool) {
+        let config = Arc::new(config);
+        let started = Arc::new(AtomicUsize::new(0));
+        let (sender, receiver) = mpsc::channel();
+
+        let workers: Vec<_> = (0..config.threads.max(1))
+            .map(|_| {
+                let config = Arc::clone(&config);
+                let started = Arc::clone(&started);
+                let sender = sender.clone();
+                thread::spawn(move || worker(&config, &started, sender))
+            })
+            .collect();
+        drop(sender);
+
+        for (i, records) in receiver.iter().enumerate() {
+            if !output(records) {
+                // Stop the workers after their current game.
+                started.store(config.games, Ordering::Relaxed);
+                break;
+            }
+            if (i + 1) % 100 == 0 {
+                eprintln!("{} / {} games", i + 1, config.games);
+            }
+        }
+        drop(receiver);
 
-    let p = board.pawns();
-    let n = board.knights();
-    let b = board.bishops();
-    let r = board.rooks();
-    let q = board.queens();
-    let k = board.kings();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+    }
 
-    for color in [white, black] {
-        for piece in [p, n, b, r, q, k] {
-            let bb = color.intersect(piece);
+    fn worker(config: &Config, started: &AtomicUsize, sender: Sender<Vec<Record>>) {
+        let mut scorer = Scorer::new(config.depth, Some(config.nodes));
+        let mut rng = thread_rng();
 
-            for sq in bb {
-                let sq = sq as usize;
-                result[index + sq] = 1;
+        while started.fetch_add(1, Ordering::Relaxed) < config.games {
+            let Some(records) =
+                (0..MAX_OPENING_TRIES).find_map(|_| play_game(config, &mut scorer, &mut rng))
+            else {
+                eprintln!("no playable position after {MAX_OPENING_TRIES} random openings");
+                return;
+            };
+            if sender.send(records).is_err() {
+                return;
             }
-            index += 64;
         }
     }
 
-    return result;
-}
+    fn opening(config: &Config, rng: &mut impl Rng) -> Option<Chess> {
+        let mut position = config.openings.choose(rng).cloned().unwrap_or_default();
 
-fn main() {
-    let mut args = env::args();
-    let _program = args.next();
-    let pgn = PgnIterator::new(args.next().unwrap());
-    let flags = args.next();
-    let interactive = if let Some(flags) = flags {
-        flags == "--interactive"
-    } else {
-        false
-    };
-    // let pgn = PgnIterator::new("lichess_db_standard_rated_2013-01.pgn".to_string());
+        for _ in 0..config.random_plies {
+            let moves = position.legal_moves();
+            let m = moves.choose(rng)?;
+            position.play_unchecked(m);
+        }
 
-    let n_max = 250_000_000;
-    let mut n = 0;
+        if position.is_game_over() {
+            None
+        } else {
+            Some(position)
+        }
+    }
 
-    let mut buf = String::new();
+    /// Returns `None` if no playable position was reached from the opening.
+    fn play_game(config: &Config, scorer: &mut Scorer, rng: &mut impl Rng) -> Option<Vec<Record>> {
+        let mut position = opening(config, rng)?;
+
+        let mut records = Vec::new();
+        let mut history = vec![position.zobrist_hash::<Zobrist64>(EnPassantMode::Legal)];
+        let mut win_plies = 0;
+        let mut draw_plies = 0;
+
+        let result = loop {
+            if let Some(outcome) = position.outcome() {
+                break match outcome {
+                    Outcome::Decisive { winner } => winner.fold_wb(1, -1),
+                    Outcome::Draw => 0,
+                };
+            }
+            if position.halfmoves() >= 100 || records.len() >= MAX_PLIES {
+                break 0;
+            }
 
-    'outer: for game in pgn {
-        let mut board = Chess::default();
-        let moves = game.moves;
-        let headers = game.headers;
+            let (m, score) = scorer.search(&position);
 
-        let termination = headers.get("Termination").unwrap();
+            if position.capture_moves().is_empty() {
+                records.push(Record::new(&position, 0, Some(score)));
+            }
 
-        if termination != r#""Normal""# {
-            continue;
-        }
+            if score.abs() >= WIN_SCORE {
+                win_plies += 1;
+                if win_plies >= WIN_PLIES {
+                    break score.signum() as i8;
+                }
+            } else {
+                win_plies = 0;
+            }
 
-        let result = headers.get("Result").unwrap();
-        let y = match result.as_str() {
-            r#""1-0""# => 1,
-            r#""0-1""# => -1,
-            r#""1/2-1/2""# => 0,
-            x => panic!("unknown result {}", x),
-        };
+            if score.abs() <= DRAW_SCORE && history.len() >= DRAW_MIN_PLY {
+                draw_plies += 1;
+                if draw_plies >= DRAW_PLIES {
+                    break 0;
+                }
+            } else {
+                draw_plies = 0;
+            }
 
-        for move_string in moves {
-            let san: San = match move_string.parse() {
-                Ok(m) => m,
-                _ => continue,
-            };
-            let m = san.to_move(&board).unwrap();
-            board = board.play(&m).unwrap();
+            position = position.play(&m).ok()?;
 
-            if board.capture_moves().len() != 0 {
-                continue;
+            let hash = position.zobrist_hash::<Zobrist64>(EnPassantMode::Legal);
+            if history.iter().filter(|h| **h == hash).count() >= 2 {
+                break 0;
             }
+            history.push(hash);
+        };
 
-            let ser = serialize(&board);
+        for record in &mut records {
+            record.result = result;
+        }
+
+        Some(records)
+    }
+}
 
-            buf.push_str(&y.to_string());
-            for x in ser {
-                buf.push(',');
-                buf.push_str(&x.to_string());
+fn main() {
+    let config = Config::from_args();
+    let max_positions = config.max_positions;
+    let interactive = config.interactive;
+
+    let mut output =
+        Output::new(config.output.clone(), config.shard_size).expect("cannot create output");
+
+    if config.selfplay {
+        let selfplay_config = selfplay::Config {
+            games: config.games,
+            threads: config.threads,
+            depth: config.depth,
+            nodes: config.nodes.unwrap_or(SELFPLAY_NODES),
+            openings: config
+                .openings
+                .map(|path| selfplay::load_openings(&path))
+                .unwrap_or_default(),
+            random_plies: config.random_plies,
+        };
+        let mut written = 0;
+        selfplay::run(selfplay_config, |records| {
+            for record in records.iter().take(max_positions - written) {
+                output.write(record).expect("cannot write output");
+                written += 1;
             }
-            buf.push('\n');
-            if interactive && buf.len() > 0 {
-                print!("{buf}");
-                buf.clear();
+            written < max_positions
+        });
+        output.flush().expect("cannot write output");
+        eprintln!("positions written: {written}");
+        return;
+    }
+
+    let mut summary = Summary::default();
+
+    run_pgn(config, |batch| {
+        summary.add(&batch.summary);
+
+        for record in batch.records.iter().take(max_positions - summary.written) {
+            output.write(record).expect("cannot write output");
+            summary.written += 1;
+
+            if interactive {
+                output.flush().expect("cannot write output");
                 let mut stdin_buffer = String::new();
                 while stdin_buffer.trim() != "next" {
                     stdin_buffer.clear();
@@ -196,20 +786,11 @@ fn main() {
                     stdin.read_line(&mut stdin_buffer).unwrap();
                 }
             }
-            n += 1;
-
-            if n >= n_max {
-                break 'outer;
-            }
         }
 
-        if !interactive {
-            print!("{buf}");
-            buf.clear();
-        }
-    }
-    if !interactive {
-        print!("{buf}");
-        buf.clear();
-    }
+        summary.written < max_positions
+    });
+
+    output.flush().expect("cannot write output");
+    summary.print();
 }
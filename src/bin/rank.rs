@@ -1,8 +1,21 @@
+// Plays games between two engines from random positions and prints the
+// wins of engine A, the draws and the wins of engine B, followed by the Elo
+// difference of A over B. Both engines play without an opening book, other
+// UCI options are given as NAME=VALUE, for example to calibrate the
+// `UCI_Elo` option:
+//
+//     rank --option-a "Skill Level=10" --games 200
+//
+// Usage:
+//     rank [--engine-a CMD] [--engine-b CMD] [--option-a NAME=VALUE]...
+//          [--option-b NAME=VALUE]... [--games N] [--positions PATH]
+
 use rand::prelude::*;
+use std::env;
 use std::io::BufRead;
 use std::io::BufReader;
 use std::io::Write;
-use std::process::{Child, Command, Stdio};
+use std::process::{exit, Child, Command, Stdio};
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::thread;
@@ -32,8 +45,8 @@ impl Engine {
         if let Some(args) = uci_args {
             if let Some(ref mut stdin) = child.stdin {
                 for arg in args {
-                    stdin.write(arg.as_bytes()).unwrap();
-                    stdin.write(&[b'\n']).unwrap();
+                    stdin.write_all(arg.as_bytes()).unwrap();
+                    stdin.write_all(b"\n").unwrap();
                 }
 
                 stdin.flush().unwrap();
@@ -85,35 +98,45 @@ impl Engine {
 
     fn command(&mut self, cmd: String) {
         if let Some(ref mut stdin) = self.handle.stdin {
-            stdin.write(cmd.as_bytes()).unwrap();
-            stdin.write(&[b'\n']).unwrap();
+            stdin.write_all(cmd.as_bytes()).unwrap();
+            stdin.write_all(b"\n").unwrap();
             stdin.flush().unwrap();
         }
     }
 
-    fn read_output(&self) -> String {
-        self.output_buffer.lock().unwrap().clone()
+    fn output_len(&self) -> usize {
+        self.output_buffer.lock().unwrap().len()
     }
 
-    fn read_last_line(&self) -> String {
-        let output = self.read_output();
-        output.split('\n').nth_back(1).unwrap().to_string()
-    }
+    /// Sends `go` and waits for the `bestmove` or `info outcome` line that
+    /// answers it.
+    fn go(&mut self, command: &str) -> String {
+        let since = self.output_len();
+        self.command(command.to_string());
 
-    fn read_uci_move(&self) -> String {
-        self.read_last_line()
-            .split_whitespace()
-            .nth(1)
-            .unwrap()
-            .to_string()
+        for _ in 0..REPLY_TIMEOUT_MS / 5 {
+            let output = self.output_buffer.lock().unwrap();
+            let reply = output[since..]
+                .lines()
+                .find(|line| line.starts_with("bestmove") || line.starts_with("info outcome"));
+            if let Some(reply) = reply {
+                return reply.to_string();
+            }
+            drop(output);
+            sleeps_ms(5);
+        }
+        panic!("no reply to {command}");
     }
 }
 
+// How long to wait for an engine to answer `go`.
+const REPLY_TIMEOUT_MS: u64 = 10_000;
+
 fn sleeps_ms(time: u64) {
     std::thread::sleep(std::time::Duration::from_millis(time));
 }
 
-fn get_random_position(fens: &Vec<String>) -> String {
+fn get_random_position(fens: &[String]) -> String {
     let i = thread_rng().gen_range(0..fens.len());
     fens[i].clone()
 }
@@ -139,37 +162,31 @@ fn simulate_game(engine_a: &mut Engine, engine_b: &mut Engine, position: String)
     engine_b.command(format!("position fen {position}"));
     sleeps_ms(50);
 
-    engine_a.command("go movetime 100".to_string());
-    sleeps_ms(150);
-    let mut moves = Vec::new();
-    moves.push(engine_a.read_uci_move());
-
-    let mut turn = false;
-    let mut i = 0;
-    loop {
-        if i > 100 {
+    let mut moves: Vec<String> = Vec::new();
+    let mut turn = true;
+    let outcome = loop {
+        if moves.len() > 101 {
             return Outcome::Draw;
         }
         let engine = if turn { &mut *engine_a } else { &mut *engine_b };
-        let command = format!("position fen {position} moves {}", moves.join(" "));
-        engine.command(command);
-        engine.command("go movetime 50".to_string());
-        sleeps_ms(150);
-        if engine.read_last_line().starts_with("info outcome") {
-            break;
+        let reply = if moves.is_empty() {
+            engine.go("go movetime 100")
+        } else {
+            engine.command(format!("position fen {position} moves {}", moves.join(" ")));
+            engine.go("go movetime 50")
+        };
+        if let Some(outcome) = reply.strip_prefix("info outcome ") {
+            break outcome.to_string();
         }
-        let uci_move = engine.read_uci_move();
-        moves.push(uci_move);
+        let uci_move = reply.split_whitespace().nth(1).unwrap_or_default();
+        moves.push(uci_move.to_string());
         turn = !turn;
-        i += 1;
-    }
+    };
 
-    let outcome = (if turn { engine_a } else { engine_b })
-        .read_last_line()
-        .split_whitespace()
-        .last()
-        .unwrap()
-        .to_string();
+    // Both engines see the final position, so they can learn from the result.
+    let command = format!("position fen {position} moves {}", moves.join(" "));
+    engine_a.command(command.clone());
+    engine_b.command(command);
 
     match outcome.as_str() {
         "1-0" => Outcome::White,
@@ -179,21 +196,93 @@ fn simulate_game(engine_a: &mut Engine, engine_b: &mut Engine, position: String)
     }
 }
 
-fn main() {
-    let path = "random_positions";
-    let fens = fens_from_file(path);
-    let mut engine_a = Engine::new(
-        &["./target/release/minikalle"],
-        Some(&["setoption name Book value false"]),
+struct Config {
+    engine_a: String,
+    engine_b: String,
+    options_a: Vec<String>,
+    options_b: Vec<String>,
+    games: u32,
+    positions: String,
+}
+
+impl Config {
+    fn from_args() -> Config {
+        let mut args = env::args().skip(1);
+
+        let mut config = Config {
+            engine_a: "./target/release/minikalle".to_string(),
+            engine_b: "./minikalle".to_string(),
+            options_a: vec!["setoption name Book value false".to_string()],
+            options_b: vec!["setoption name Book value false".to_string()],
+            games: 1000,
+            positions: "random_positions".to_string(),
+        };
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .unwrap_or_else(|| usage(&format!("missing value for {name}")))
+            };
+            match arg.as_str() {
+                "--engine-a" => config.engine_a = value(&arg),
+                "--engine-b" => config.engine_b = value(&arg),
+                "--option-a" => config.options_a.push(setoption(&value(&arg))),
+                "--option-b" => config.options_b.push(setoption(&value(&arg))),
+                "--games" => config.games = parse(&value(&arg), &arg),
+                "--positions" => config.positions = value(&arg),
+                x => usage(&format!("unknown argument {x}")),
+            }
+        }
+
+        config
+    }
+}
+
+fn setoption(option: &str) -> String {
+    let Some((name, value)) = option.split_once('=') else {
+        usage(&format!("invalid option {option}, expected NAME=VALUE"));
+    };
+    format!("setoption name {name} value {value}")
+}
+
+fn parse<T: std::str::FromStr>(value: &str, name: &str) -> T {
+    value
+        .parse()
+        .unwrap_or_else(|_| usage(&format!("invalid value {value} for {name}")))
+}
+
+fn usage(message: &str) -> ! {
+    eprintln!("{message}");
+    eprintln!(
+        "usage: rank [--engine-a CMD] [--engine-b CMD] [--option-a NAME=VALUE]... \
+         [--option-b NAME=VALUE]... [--games N] [--positions PATH]"
     );
-    let mut engine_b = Engine::new(&["./minikalle"], Some(&["setoption name Book value false"]));
+    exit(1);
+}
+
+/// Elo difference that gives the expected `score`, between 0 and 1.
+fn elo_difference(score: f64) -> f64 {
+    let score = score.clamp(0.001, 0.999);
+    -400.0 * (1.0 / score - 1.0).log10()
+}
+
+fn main() {
+    let config = Config::from_args();
+    let fens = fens_from_file(&config.positions);
+    let engine = |command: &str, options: &[String]| {
+        let command: Vec<&str> = command.split_whitespace().collect();
+        let options: Vec<&str> = options.iter().map(String::as_str).collect();
+        Engine::new(&command, Some(&options))
+    };
+    let mut engine_a = engine(&config.engine_a, &config.options_a);
+    let mut engine_b = engine(&config.engine_b, &config.options_b);
     sleeps_ms(50);
 
     let mut a_score = 0;
     let mut b_score = 0;
     let mut draws = 0;
 
-    let n = 1000;
+    let n = config.games;
 
     for i in 0..n {
         eprintln!("{i} / {n} - {a_score} {draws} {b_score}");
@@ -226,5 +315,7 @@ fn main() {
     engine_a.handle.wait().unwrap();
     engine_b.handle.wait().unwrap();
 
-    println!("{a_score} {draws} {b_score}")
+    println!("{a_score} {draws} {b_score}");
+    let score = (a_score as f64 + 0.5 * draws as f64) / n as f64;
+    println!("elo {:+.0}", elo_difference(score));
 }
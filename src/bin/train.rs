@@ -0,0 +1,654 @@
+// Trains the evaluation network on the output of `generate_data`.
+// Older CSV data can be upgraded with the `convert` binary.
+//
+// This is a CPU port of `neural_evaluator/train.py` so the network can be
+// retrained without python. The trained weights are written as the `W*.in` and
+// `B*.in` files that `src/neural_eval.rs` includes from `src/model`. They are
+// exported to `--export DIR` after every epoch, so the compiled-in weights are
+// only replaced when `src/model` is given explicitly.
+//
+// Usage:
+//     train <data file or directory> --export DIR [--epochs N] [--batch-size N]
+//           [--lr X] [--loss mse|wdl] [--lambda X] [--checkpoint PATH] [--resume]
+//
+// `--lambda` blends the search score into the target for positions that were
+// scored by `generate_data`, 0 trains on game results only and 1 on scores only.
+
+use minikalle::trainingdata::{Record, RecordReader, FEATURES};
+use rand::prelude::*;
+use std::env;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::exit;
+
+// Must match the network in `src/neural_eval.rs`.
+const LAYERS: [usize; 6] = [FEATURES, 32, 16, 8, 8, 1];
+// Must match `WEIGHT_SCALES` in `src/neural_eval.rs`.
+const WEIGHT_SCALES: [f32; 5] = [32.0, 32.0, 32.0, 32.0, 32.0];
+// Must match `OUTPUT_SCALE` in `src/neural_eval.rs`, centipawns per 1.0 of output.
+const OUTPUT_SCALE: f32 = 32.0;
+// The WDL loss maps centipawns to a winning chance with sigmoid(cp / WDL_SCALE).
+// Search scores are mapped to [-1, 1] on the same scale, 2 * sigmoid(cp / WDL_SCALE) - 1.
+const WDL_SCALE: f32 = 400.0;
+
+const BETA1: f32 = 0.9;
+const BETA2: f32 = 0.999;
+const EPSILON: f32 = 1e-8;
+
+const CHECKPOINT_MAGIC: &[u8; 4] = b"MKTR";
+
+#[derive(Clone, Copy, Debug)]
+enum Loss {
+    /// Mean squared error between the output and the target.
+    Mse,
+    /// Mean squared error between the winning chance of the output in
+    /// centipawns, as the engine reads it, and the target mapped to [0, 1].
+    Wdl,
+}
+
+struct Config {
+    data: PathBuf,
+    epochs: usize,
+    batch_size: usize,
+    learning_rate: f32,
+    loss: Loss,
+    lambda: f32,
+    checkpoint: PathBuf,
+    resume: bool,
+    export: PathBuf,
+}
+
+impl Config {
+    fn from_args() -> Config {
+        let mut args = env::args().skip(1);
+
+        let mut config = Config {
+            data: PathBuf::new(),
+            epochs: 1_000,
+            batch_size: 16_384,
+            learning_rate: 0.001,
+            loss: Loss::Mse,
+            lambda: 0.0,
+            checkpoint: PathBuf::from("value.ckpt"),
+            resume: false,
+            export: PathBuf::new(),
+        };
+
+        let mut data = None;
+        let mut export = None;
+
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| {
+                args.next()
+                    .unwrap_or_else(|| usage(&format!("missing value for {name}")))
+            };
+            match arg.as_str() {
+                "--epochs" => config.epochs = parse(&value(&arg), &arg),
+                "--batch-size" => config.batch_size = parse(&value(&arg), &arg),
+                "--lr" => config.learning_rate = parse(&value(&arg), &arg),
+                "--loss" => {
+                    config.loss = match value(&arg).as_str() {
+                        "mse" => Loss::Mse,
+                        "wdl" => Loss::Wdl,
+                        x => usage(&format!("unknown loss {x}")),
+                    }
+                }
+                "--lambda" => config.lambda = parse(&value(&arg), &arg),
+                "--checkpoint" => config.checkpoint = PathBuf::from(value(&arg)),
+                "--resume" => config.resume = true,
+                "--export" => export = Some(PathBuf::from(value(&arg))),
+                x if x.starts_with("--") => usage(&format!("unknown flag {x}")),
+                _ => data = Some(PathBuf::from(arg)),
+            }
+        }
+
+        config.data = data.unwrap_or_else(|| usage("missing data path"));
+        config.export = export.unwrap_or_else(|| usage("missing --export directory"));
+
+        if config.batch_size == 0 {
+            usage("batch size must be positive");
+        }
+        if !(0.0..=1.0).contains(&config.lambda) {
+            usage("lambda must be between 0 and 1");
+        }
+
+        config
+    }
+}
+
+fn parse<T: std::str::FromStr>(value: &str, name: &str) -> T {
+    value
+        .parse()
+        .unwrap_or_else(|_| usage(&format!("invalid value {value} for {name}")))
+}
+
+fn usage(message: &str) -> ! {
+    eprintln!("{message}");
+    eprintln!(
+        "usage: train <data> --export DIR [--epochs N] [--batch-size N] [--lr X] \
+         [--loss mse|wdl] [--lambda X] [--checkpoint PATH] [--resume]"
+    );
+    exit(1);
+}
+
+struct Sample {
+    /// Indices of the non-zero inputs.
+    features: Vec<u16>,
+    /// Game result from white's point of view, 1, 0 or -1.
+    result: f32,
+    /// Search score from white's point of view, mapped to [-1, 1].
+    score: Option<f32>,
+}
+
+impl Sample {
+    fn target(&self, lambda: f32) -> f32 {
+        match self.score {
+            Some(score) => lambda * score + (1.0 - lambda) * self.result,
+            None => self.result,
+        }
+    }
+}
+
+impl From<Record> for Sample {
+    fn from(record: Record) -> Sample {
+        Sample {
+            features: record.features().map(|f| f as u16).collect(),
+            result: record.result as f32,
+            score: record
+                .score
+                .map(|x| 2.0 * sigmoid(x as f32 / WDL_SCALE) - 1.0),
+        }
+    }
+}
+
+fn data_files(path: &Path) -> Vec<PathBuf> {
+    if path.is_file() {
+        return vec![path.to_path_buf()];
+    }
+
+    let mut files: Vec<PathBuf> = fs::read_dir(path)
+        .expect("cannot read data directory")
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file())
+        .collect();
+    files.sort();
+    files
+}
+
+#[derive(Clone)]
+struct Layer {
+    inputs: usize,
+    /// Row-major `[output][input]`, same as pytorch.
+    weights: Vec<f32>,
+    biases: Vec<f32>,
+}
+
+impl Layer {
+    fn zeros(inputs: usize, outputs: usize) -> Layer {
+        Layer {
+            inputs,
+            weights: vec![0.0; inputs * outputs],
+            biases: vec![0.0; outputs],
+        }
+    }
+
+    /// Same initialization as `torch.nn.Linear`.
+    fn random(inputs: usize, outputs: usize, rng: &mut impl Rng) -> Layer {
+        let bound = 1.0 / (inputs as f32).sqrt();
+        let mut layer = Layer::zeros(inputs, outputs);
+        for x in layer.params_mut() {
+            *x = rng.gen_range(-bound..bound);
+        }
+        layer
+    }
+
+    fn params_mut(&mut self) -> impl Iterator<Item = &mut f32> {
+        self.weights.iter_mut().chain(self.biases.iter_mut())
+    }
+
+    fn params(&self) -> impl Iterator<Item = &f32> {
+        self.weights.iter().chain(self.biases.iter())
+    }
+}
+
+#[derive(Clone)]
+struct Network {
+    layers: Vec<Layer>,
+}
+
+impl Network {
+    fn zeros() -> Network {
+        Network {
+            layers: LAYERS
+                .windows(2)
+                .map(|l| Layer::zeros(l[0], l[1]))
+                .collect(),
+        }
+    }
+
+    /// A network of zeros with the shape of `self`.
+    fn zeros_like(&self) -> Network {
+        Network {
+            layers: self
+                .layers
+                .iter()
+                .map(|l| Layer::zeros(l.inputs, l.biases.len()))
+                .collect(),
+        }
+    }
+
+    fn random(rng: &mut impl Rng) -> Network {
+        Network {
+            layers: LAYERS
+                .windows(2)
+                .map(|l| Layer::random(l[0], l[1], rng))
+                .collect(),
+        }
+    }
+
+    fn params_mut(&mut self) -> impl Iterator<Item = &mut f32> {
+        self.layers.iter_mut().flat_map(|l| l.params_mut())
+    }
+
+    fn params(&self) -> impl Iterator<Item = &f32> {
+        self.layers.iter().flat_map(|l| l.params())
+    }
+
+    /// Returns the pre-activation values of every layer.
+    fn forward(&self, features: &[u16]) -> Vec<Vec<f32>> {
+        let mut zs: Vec<Vec<f32>> = Vec::with_capacity(self.layers.len());
+
+        // The input is sparse, so the first layer only sums the active columns.
+        let first = &self.layers[0];
+        let mut z = first.biases.clone();
+        for (o, z) in z.iter_mut().enumerate() {
+            let row = &first.weights[o * first.inputs..(o + 1) * first.inputs];
+            *z += features.iter().map(|f| row[*f as usize]).sum::<f32>();
+        }
+        zs.push(z);
+
+        for layer in &self.layers[1..] {
+            let a: Vec<f32> = zs
+                .last()
+                .unwrap()
+                .iter()
+                .map(|z| clipped_relu(*z))
+                .collect();
+            let z = layer
+                .weights
+                .chunks_exact(layer.inputs)
+                .zip(&layer.biases)
+                .map(|(row, b)| row.iter().zip(&a).map(|(w, a)| w * a).sum::<f32>() + b)
+                .collect();
+            zs.push(z);
+        }
+
+        zs
+    }
+
+    /// Adds the gradient of the loss with respect to every parameter to `grads`,
+    /// given the derivative of the loss with respect to the network output.
+    fn backward(&self, features: &[u16], zs: &[Vec<f32>], d_output: f32, grads: &mut Network) {
+        let mut dz = vec![d_output];
+
+        for l in (1..self.layers.len()).rev() {
+            let layer = &self.layers[l];
+            let grad = &mut grads.layers[l];
+            let z_prev = &zs[l - 1];
+
+            let mut dz_prev = vec![0.0; layer.inputs];
+            for (o, d) in dz.iter().enumerate() {
+                grad.biases[o] += d;
+                let row = o * layer.inputs;
+                for i in 0..layer.inputs {
+                    grad.weights[row + i] += d * clipped_relu(z_prev[i]);
+                    dz_prev[i] += d * layer.weights[row + i];
+                }
+            }
+            for (d, z) in dz_prev.iter_mut().zip(z_prev) {
+                *d *= clipped_relu_derivative(*z);
+            }
+            dz = dz_prev;
+        }
+
+        let grad = &mut grads.layers[0];
+        for (o, d) in dz.iter().enumerate() {
+            grad.biases[o] += d;
+            let row = o * grad.inputs;
+            for f in features {
+                grad.weights[row + *f as usize] += d;
+            }
+        }
+    }
+
+    fn export(&self, dir: &Path) -> std::io::Result<()> {
+        fs::create_dir_all(dir)?;
+        for (i, layer) in self.layers.iter().enumerate() {
+            write_quantized(
+                &dir.join(format!("W{i}.in")),
+                &layer.weights,
+                WEIGHT_SCALES[i],
+            )?;
+            write_quantized(
+                &dir.join(format!("B{i}.in")),
+                &layer.biases,
+                WEIGHT_SCALES[i],
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the values in the same format as `neural_evaluator/export_weights.py`.
+fn write_quantized(path: &Path, values: &[f32], scale: f32) -> std::io::Result<()> {
+    let mut f = BufWriter::new(File::create(path)?);
+    f.write_all(b"[")?;
+    for x in values {
+        let x = (x * scale).round().clamp(-128.0, 127.0) as i32;
+        write!(f, "{x},")?;
+    }
+    f.write_all(b"]")?;
+    f.flush()
+}
+
+#[inline]
+fn clipped_relu(x: f32) -> f32 {
+    x.clamp(0.0, 1.0)
+}
+
+#[inline]
+fn clipped_relu_derivative(x: f32) -> f32 {
+    if x > 0.0 && x < 1.0 {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+#[inline]
+fn sigmoid(x: f32) -> f32 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Returns the loss and its derivative with respect to the output.
+fn loss(loss: Loss, output: f32, target: f32) -> (f32, f32) {
+    match loss {
+        Loss::Mse => {
+            let error = output - target;
+            (error * error, 2.0 * error)
+        }
+        Loss::Wdl => {
+            let k = OUTPUT_SCALE / WDL_SCALE;
+            let p = sigmoid(output * k);
+            let error = p - (target + 1.0) / 2.0;
+            (error * error, 2.0 * error * p * (1.0 - p) * k)
+        }
+    }
+}
+
+struct Adam {
+    m: Network,
+    v: Network,
+    step: u64,
+}
+
+impl Adam {
+    fn new() -> Adam {
+        Adam::for_network(&Network::zeros())
+    }
+
+    fn for_network(network: &Network) -> Adam {
+        Adam {
+            m: network.zeros_like(),
+            v: network.zeros_like(),
+            step: 0,
+        }
+    }
+
+    fn update(&mut self, network: &mut Network, grads: &Network, learning_rate: f32) {
+        self.step += 1;
+        let correction1 = 1.0 - BETA1.powi(self.step as i32);
+        let correction2 = 1.0 - BETA2.powi(self.step as i32);
+
+        let params = network
+            .params_mut()
+            .zip(self.m.params_mut())
+            .zip(self.v.params_mut())
+            .zip(grads.params());
+
+        for (((x, m), v), g) in params {
+            *m = BETA1 * *m + (1.0 - BETA1) * g;
+            *v = BETA2 * *v + (1.0 - BETA2) * g * g;
+            let m_hat = *m / correction1;
+            let v_hat = *v / correction2;
+            *x -= learning_rate * m_hat / (v_hat.sqrt() + EPSILON);
+        }
+    }
+}
+
+/// Checkpoints store the network and optimizer state as little endian f32s.
+fn save_checkpoint(path: &Path, network: &Network, adam: &Adam) -> std::io::Result<()> {
+    let mut f = BufWriter::new(File::create(path)?);
+    f.write_all(CHECKPOINT_MAGIC)?;
+    f.write_all(&adam.step.to_le_bytes())?;
+    for net in [network, &adam.m, &adam.v] {
+        for x in net.params() {
+            f.write_all(&x.to_le_bytes())?;
+        }
+    }
+    f.flush()
+}
+
+fn load_checkpoint(path: &Path) -> std::io::Result<(Network, Adam)> {
+    let mut f = BufReader::new(File::open(path)?);
+
+    let invalid = |message| std::io::Error::new(std::io::ErrorKind::InvalidData, message);
+
+    let mut magic = [0; 4];
+    f.read_exact(&mut magic)?;
+    if &magic != CHECKPOINT_MAGIC {
+        return Err(invalid("not a checkpoint file"));
+    }
+
+    let mut step = [0; 8];
+    f.read_exact(&mut step)?;
+
+    let mut network = Network::zeros();
+    let mut adam = Adam::new();
+    adam.step = u64::from_le_bytes(step);
+
+    for net in [&mut network, &mut adam.m, &mut adam.v] {
+        for x in net.params_mut() {
+            let mut bytes = [0; 4];
+            f.read_exact(&mut bytes)
+                .map_err(|_| invalid("checkpoint does not match the network"))?;
+            *x = f32::from_le_bytes(bytes);
+        }
+    }
+
+    if f.read(&mut [0])? != 0 {
+        return Err(invalid("checkpoint does not match the network"));
+    }
+
+    Ok((network, adam))
+}
+
+fn train_batch(network: &mut Network, adam: &mut Adam, batch: &[Sample], config: &Config) -> f32 {
+    let mut grads = network.zeros_like();
+    let mut total_loss = 0.0;
+    let n = batch.len() as f32;
+
+    for sample in batch {
+        let zs = network.forward(&sample.features);
+        let output = zs.last().unwrap()[0];
+        let (l, d_output) = loss(config.loss, output, sample.target(config.lambda));
+        total_loss += l;
+        network.backward(&sample.features, &zs, d_output / n, &mut grads);
+    }
+
+    adam.update(network, &grads, config.learning_rate);
+
+    total_loss / n
+}
+
+fn main() {
+    let config = Config::from_args();
+    let mut rng = thread_rng();
+
+    let files = data_files(&config.data);
+    if files.is_empty() {
+        usage("no training data found");
+    }
+
+    let (mut network, mut adam) = if config.resume {
+        match load_checkpoint(&config.checkpoint) {
+            Ok(state) => {
+                eprintln!("warm start from {}", config.checkpoint.display());
+                state
+            }
+            Err(err) => {
+                eprintln!("cannot load {}: {err}", config.checkpoint.display());
+                exit(1);
+            }
+        }
+    } else {
+        (Network::random(&mut rng), Adam::new())
+    };
+
+    // Samples are shuffled within a buffer of this many batches.
+    let buffer_size = config.batch_size * 8;
+
+    for epoch in 0..config.epochs {
+        let mut files = files.clone();
+        files.shuffle(&mut rng);
+
+        let mut all_loss = 0.0;
+        let mut num_loss = 0;
+        let mut buffer: Vec<Sample> = Vec::with_capacity(buffer_size);
+
+        let mut run_buffer = |buffer: &mut Vec<Sample>, network: &mut Network, adam: &mut Adam| {
+            buffer.shuffle(&mut rng);
+            for batch in buffer.chunks(config.batch_size) {
+                all_loss += train_batch(network, adam, batch, &config);
+                num_loss += 1;
+            }
+            buffer.clear();
+        };
+
+        for file in &files {
+            let file = File::open(file).expect("cannot open data file");
+            let reader = RecordReader::new(BufReader::new(file)).expect("cannot read data file");
+            for record in reader {
+                buffer.push(record.expect("cannot read data file").into());
+                if buffer.len() == buffer_size {
+                    run_buffer(&mut buffer, &mut network, &mut adam);
+                }
+            }
+        }
+        run_buffer(&mut buffer, &mut network, &mut adam);
+
+        println!(
+            "Epoch [{}], Loss: {:.4}",
+            epoch + 1,
+            all_loss / num_loss.max(1) as f32
+        );
+
+        save_checkpoint(&config.checkpoint, &network, &adam).expect("cannot write checkpoint");
+        network
+            .export(&config.export)
+            .expect("cannot export weights");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+
+    fn tiny_network(rng: &mut impl Rng) -> Network {
+        Network {
+            layers: vec![
+                Layer::random(6, 4, rng),
+                Layer::random(4, 3, rng),
+                Layer::random(3, 1, rng),
+            ],
+        }
+    }
+
+    fn sample_loss(network: &Network, features: &[u16], kind: Loss, result: f32) -> f32 {
+        let output = network.forward(features).last().unwrap()[0];
+        loss(kind, output, result).0
+    }
+
+    #[test]
+    fn test_gradient() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let features = [0, 2, 5];
+        // Small first layer weights keep the hidden units away from the kinks
+        // of the clipped ReLU.
+        for kind in [Loss::Mse, Loss::Wdl] {
+            let mut network = tiny_network(&mut rng);
+            for x in network.layers[0].params_mut() {
+                *x = 0.15 + *x * 0.1;
+            }
+
+            let zs = network.forward(&features);
+            let output = zs.last().unwrap()[0];
+            let (_, d_output) = loss(kind, output, 1.0);
+            let mut grads = network.zeros_like();
+            network.backward(&features, &zs, d_output, &mut grads);
+
+            let epsilon = 1e-3;
+            let analytic: Vec<f32> = grads.params().copied().collect();
+            assert!(grads.layers[0].params().any(|g| *g != 0.0));
+            for (i, expected) in analytic.iter().enumerate() {
+                let mut plus = network.clone();
+                *plus.params_mut().nth(i).unwrap() += epsilon;
+                let mut minus = network.clone();
+                *minus.params_mut().nth(i).unwrap() -= epsilon;
+                let numeric = (sample_loss(&plus, &features, kind, 1.0)
+                    - sample_loss(&minus, &features, kind, 1.0))
+                    / (2.0 * epsilon);
+                assert!(
+                    (numeric - expected).abs() < 1e-3 + 1e-2 * expected.abs(),
+                    "{kind:?} parameter {i}: {numeric} != {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_adam_step() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let mut network = tiny_network(&mut rng);
+        let before = network.clone();
+        let mut grads = network.zeros_like();
+        for (i, g) in grads.params_mut().enumerate() {
+            *g = [0.5, -2.0, 0.0][i % 3];
+        }
+
+        // The first bias corrected step moves every parameter by the learning
+        // rate against the sign of its gradient.
+        let mut adam = Adam::for_network(&network);
+        adam.update(&mut network, &grads, 0.01);
+        assert_eq!(adam.step, 1);
+        for ((x, x0), g) in network.params().zip(before.params()).zip(grads.params()) {
+            let expected = if *g == 0.0 {
+                *x0
+            } else {
+                x0 - 0.01 * g.signum()
+            };
+            assert!((x - expected).abs() < 1e-5, "{x} != {expected}");
+        }
+    }
+
+    #[test]
+    fn test_write_quantized() {
+        let path = env::temp_dir().join(format!("minikalle-train-{}.in", std::process::id()));
+        write_quantized(&path, &[0.1, 0.11, -0.11, -0.1, 10.0, -10.0], 32.0).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(text, "[3,4,-4,-3,127,-128,]");
+    }
+}
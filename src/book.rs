@@ -0,0 +1,475 @@
+// Opening books and book move selection.
+//
+// The default book is `gm_openings.json`, a JSON map from shakmaty Zobrist
+// hashes to the UCI moves played in that position, with a move repeated once
+// for every game it was played in. The repeats are counted into weights when
+// the book is loaded. A move can also be given once as a pair of the move and
+// its weight, which is how `build_book` writes its JSON books. The default
+// book is embedded in the binary with the `embedded-book` feature and
+// otherwise loaded from `DEFAULT_BOOK_FILE` the first time the engine looks
+// for a book move.
+//
+// A learning file keeps the results of the games the engine played book moves
+// in, one `<zobrist> <uci move> <wins> <draws> <losses>` line per move, and
+// book moves that lost are played less often.
+
+use crate::polyglot;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde_json::Value;
+use shakmaty::uci::Uci;
+use shakmaty::zobrist::{Zobrist64, ZobristHash};
+use shakmaty::{CastlingMode, Chess, EnPassantMode, Move, Outcome, Position};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+
+/// Looked up in the working directory and then next to the executable.
+pub const DEFAULT_BOOK_FILE: &str = "gm_openings.json";
+
+#[cfg(feature = "embedded-book")]
+const EMBEDDED_BOOK: &str = include_str!("../gm_openings.json");
+
+#[derive(Debug)]
+pub enum BookError {
+    Io(PathBuf, io::Error),
+    Json(PathBuf, serde_json::Error),
+    /// Malformed line in a learning file.
+    Learning(PathBuf, usize),
+}
+
+impl fmt::Display for BookError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BookError::Io(path, err) => write!(f, "cannot read book {}: {err}", path.display()),
+            BookError::Json(path, err) => write!(f, "invalid book {}: {err}", path.display()),
+            BookError::Learning(path, line) => {
+                write!(f, "invalid learning file {}, line {line}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for BookError {}
+
+/// How a move is picked among the book moves of a position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Selection {
+    /// Always the move with the highest weight.
+    Best,
+    /// At random in proportion to the weights.
+    Weighted,
+    /// At random, ignoring the weights.
+    Uniform,
+}
+
+impl FromStr for Selection {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Selection, ()> {
+        match s.to_lowercase().as_str() {
+            "best" => Ok(Selection::Best),
+            "weighted" => Ok(Selection::Weighted),
+            "uniform" => Ok(Selection::Uniform),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BookMove {
+    pub uci: Uci,
+    pub weight: u32,
+}
+
+pub struct JsonBook {
+    positions: HashMap<u64, Vec<BookMove>>,
+}
+
+impl JsonBook {
+    pub fn from_json(text: &str) -> serde_json::Result<JsonBook> {
+        let raw: HashMap<u64, Vec<Value>> = serde_json::from_str(text)?;
+
+        let positions = raw
+            .into_iter()
+            .map(|(zobrist, moves)| {
+                let mut book_moves: Vec<BookMove> = Vec::new();
+                for (uci, weight) in moves.iter().filter_map(JsonBook::parse_move) {
+                    match book_moves.iter_mut().find(|m| m.uci == uci) {
+                        Some(m) => m.weight = m.weight.saturating_add(weight),
+                        None => book_moves.push(BookMove { uci, weight }),
+                    }
+                }
+                (zobrist, book_moves)
+            })
+            .collect();
+
+        Ok(JsonBook { positions })
+    }
+
+    /// Either `"e2e4"` with a weight of 1 or `["e2e4", 12]`.
+    fn parse_move(value: &Value) -> Option<(Uci, u32)> {
+        match value {
+            Value::String(uci) => Some((uci.parse().ok()?, 1)),
+            Value::Array(pair) => match pair.as_slice() {
+                [Value::String(uci), weight] => Some((
+                    uci.parse().ok()?,
+                    u32::try_from(weight.as_u64()?).unwrap_or(u32::MAX),
+                )),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Legal book moves in `position` with their weights.
+    pub fn moves(&self, position: &Chess) -> Vec<(Move, u32)> {
+        let zobrist = position.zobrist_hash::<Zobrist64>(EnPassantMode::Legal);
+        self.positions
+            .get(&zobrist.0)
+            .into_iter()
+            .flatten()
+            .filter_map(|m| Some((m.uci.to_move(position).ok()?, m.weight)))
+            .collect()
+    }
+}
+
+pub enum Book {
+    Json(JsonBook),
+    Polyglot(polyglot::Book),
+}
+
+impl Book {
+    /// Reads a Polyglot book if the file name ends in `.bin` and a JSON book
+    /// otherwise.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Book, BookError> {
+        let path = path.as_ref();
+        if path.extension().is_some_and(|ext| ext == "bin") {
+            let book = polyglot::Book::open(path).map_err(|e| BookError::Io(path.into(), e))?;
+            Ok(Book::Polyglot(book))
+        } else {
+            let text = fs::read_to_string(path).map_err(|e| BookError::Io(path.into(), e))?;
+            let book = JsonBook::from_json(&text).map_err(|e| BookError::Json(path.into(), e))?;
+            Ok(Book::Json(book))
+        }
+    }
+
+    /// Legal book moves in `position` with their weights.
+    pub fn moves(&self, position: &Chess) -> Vec<(Move, u32)> {
+        match self {
+            Book::Json(book) => book.moves(position),
+            Book::Polyglot(book) => book
+                .moves(position)
+                .into_iter()
+                .map(|(m, w)| (m, u32::from(w)))
+                .collect(),
+        }
+    }
+}
+
+/// The default book, loaded once and shared by all engines. `None` if it
+/// could not be loaded, the reason is printed the first time.
+pub fn default_book() -> Option<Arc<Book>> {
+    static DEFAULT_BOOK: OnceLock<Option<Arc<Book>>> = OnceLock::new();
+
+    DEFAULT_BOOK
+        .get_or_init(|| match load_default_book() {
+            Ok(book) => Some(Arc::new(book)),
+            Err(err) => {
+                eprintln!("{err}, playing without an opening book");
+                None
+            }
+        })
+        .clone()
+}
+
+#[cfg(feature = "embedded-book")]
+fn load_default_book() -> Result<Book, BookError> {
+    JsonBook::from_json(EMBEDDED_BOOK)
+        .map(Book::Json)
+        .map_err(|e| BookError::Json(PathBuf::from(DEFAULT_BOOK_FILE), e))
+}
+
+#[cfg(not(feature = "embedded-book"))]
+fn load_default_book() -> Result<Book, BookError> {
+    let next_to_executable = std::env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.parent()?.join(DEFAULT_BOOK_FILE)));
+
+    match next_to_executable {
+        Some(path) if !Path::new(DEFAULT_BOOK_FILE).exists() && path.exists() => Book::open(path),
+        _ => Book::open(DEFAULT_BOOK_FILE),
+    }
+}
+
+/// Results of the games a book move was played in, from the point of view of
+/// the side that played it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LearnedResult {
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+}
+
+impl LearnedResult {
+    /// Multiplier for the book weight of the move. The score is estimated
+    /// with one extra win and loss so that a single game does not decide it,
+    /// and only moves that score below 50% are weighted down.
+    fn factor(&self) -> f64 {
+        let games = f64::from(self.wins + self.draws + self.losses);
+        let score = (f64::from(self.wins) + 0.5 * f64::from(self.draws) + 1.0) / (games + 2.0);
+        (2.0 * score).min(1.0)
+    }
+}
+
+pub struct Learning {
+    path: PathBuf,
+    results: HashMap<(u64, String), LearnedResult>,
+}
+
+impl Learning {
+    /// Reads the learning file at `path`, which is created on the first
+    /// `save` if it does not exist.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Learning, BookError> {
+        let path = path.as_ref().to_path_buf();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(BookError::Io(path, err)),
+        };
+
+        let mut results = HashMap::new();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let parsed = match fields[..] {
+                [zobrist, uci, wins, draws, losses] => (|| {
+                    let result = LearnedResult {
+                        wins: wins.parse().ok()?,
+                        draws: draws.parse().ok()?,
+                        losses: losses.parse().ok()?,
+                    };
+                    Some(((zobrist.parse().ok()?, uci.to_string()), result))
+                })(),
+                _ => None,
+            };
+            let (key, result) = parsed.ok_or(BookError::Learning(path.clone(), i + 1))?;
+            results.insert(key, result);
+        }
+
+        Ok(Learning { path, results })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut lines: Vec<_> = self.results.iter().collect();
+        lines.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut writer = BufWriter::new(File::create(&self.path)?);
+        for ((zobrist, uci), r) in lines {
+            writeln!(
+                writer,
+                "{zobrist} {uci} {} {} {}",
+                r.wins, r.draws, r.losses
+            )?;
+        }
+        writer.flush()
+    }
+
+    pub fn get(&self, position: &Chess, m: &Move) -> Option<LearnedResult> {
+        self.results.get(&key(position, m)).copied()
+    }
+
+    /// Records the outcome of a game in which `m` was played in `position`.
+    pub fn record(&mut self, position: &Chess, m: &Move, outcome: Outcome) {
+        let result = self.results.entry(key(position, m)).or_default();
+        match outcome {
+            Outcome::Draw => result.draws += 1,
+            Outcome::Decisive { winner } if winner == position.turn() => result.wins += 1,
+            Outcome::Decisive { .. } => result.losses += 1,
+        }
+    }
+
+    /// Scales the weights of `moves` by their results in earlier games.
+    pub fn adjust(&self, position: &Chess, moves: &mut [(Move, u32)]) {
+        for (m, weight) in moves {
+            if let Some(result) = self.get(position, m) {
+                *weight = (f64::from(*weight) * result.factor()).round() as u32;
+            }
+        }
+    }
+}
+
+fn key(position: &Chess, m: &Move) -> (u64, String) {
+    let zobrist = position.zobrist_hash::<Zobrist64>(EnPassantMode::Legal);
+    (zobrist.0, m.to_uci(CastlingMode::Standard).to_string())
+}
+
+/// Picks one of `moves`. Moves weighted below `100 - variety` percent of the
+/// best move are never picked, so a variety of 0 only keeps the moves tied
+/// for best and 100 keeps every move with a non-zero weight.
+pub fn choose(
+    mut moves: Vec<(Move, u32)>,
+    selection: Selection,
+    variety: u8,
+    rng: &mut impl Rng,
+) -> Option<Move> {
+    let best = moves.iter().map(|(_, w)| *w).max()?;
+    let min_weight = u64::from(best) * u64::from(100 - variety.min(100)) / 100;
+    moves.retain(|(_, w)| *w > 0 && u64::from(*w) >= min_weight);
+
+    let (m, _) = match selection {
+        Selection::Best => moves.iter().find(|(_, w)| *w == best)?,
+        Selection::Weighted => moves.choose_weighted(rng, |(_, w)| *w).ok()?,
+        Selection::Uniform => moves.choose(rng)?,
+    };
+    Some(m.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use shakmaty::Color;
+    use std::env;
+
+    use super::*;
+
+    fn uci_moves(moves: &[(&str, u32)]) -> Vec<(Move, u32)> {
+        let position = Chess::new();
+        moves
+            .iter()
+            .map(|(uci, w)| {
+                let uci: Uci = uci.parse().unwrap();
+                (uci.to_move(&position).unwrap(), *w)
+            })
+            .collect()
+    }
+
+    fn to_uci(m: Option<Move>) -> String {
+        m.unwrap().to_uci(CastlingMode::Standard).to_string()
+    }
+
+    #[test]
+    fn test_json_weights() {
+        let key = Chess::new()
+            .zobrist_hash::<Zobrist64>(EnPassantMode::Legal)
+            .0;
+        let json = format!(r#"{{"{key}":["e2e4","d2d4","e2e4","e2e5","e2e4"]}}"#);
+        let book = JsonBook::from_json(&json).unwrap();
+
+        let moves: Vec<(String, u32)> = book
+            .moves(&Chess::new())
+            .into_iter()
+            .map(|(m, w)| (m.to_uci(CastlingMode::Standard).to_string(), w))
+            .collect();
+        assert_eq!(moves, [("e2e4".to_string(), 3), ("d2d4".to_string(), 1)]);
+
+        let json = format!(r#"{{"{key}":[["e2e4",12],"d2d4",["d2d4",2],["c2c4"]]}}"#);
+        let book = JsonBook::from_json(&json).unwrap();
+        let moves: Vec<(String, u32)> = book
+            .moves(&Chess::new())
+            .into_iter()
+            .map(|(m, w)| (m.to_uci(CastlingMode::Standard).to_string(), w))
+            .collect();
+        assert_eq!(moves, [("e2e4".to_string(), 12), ("d2d4".to_string(), 3)]);
+
+        assert!(JsonBook::from_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_open() {
+        let dir = env::temp_dir();
+
+        let path = dir.join("minikalle_test_book.json");
+        fs::write(&path, r#"{"1": ["e2e4"], "2": "#).unwrap();
+        let err = Book::open(&path).err().unwrap();
+        assert!(matches!(err, BookError::Json(..)));
+        assert!(err.to_string().starts_with("invalid book"));
+        fs::remove_file(&path).unwrap();
+
+        let err = Book::open(dir.join("minikalle_missing_book.bin"))
+            .err()
+            .unwrap();
+        assert!(matches!(err, BookError::Io(..)));
+
+        let book = Book::open(DEFAULT_BOOK_FILE).unwrap();
+        assert!(!book.moves(&Chess::new()).is_empty());
+    }
+
+    #[test]
+    fn test_learning() {
+        let path = env::temp_dir().join("minikalle_test_learning.txt");
+        let _ = fs::remove_file(&path);
+
+        let position = Chess::new();
+        let mut moves = uci_moves(&[("e2e4", 10), ("d2d4", 10)]);
+        let (e4, d4) = (moves[0].0.clone(), moves[1].0.clone());
+
+        let mut learning = Learning::open(&path).unwrap();
+        let black_wins = Outcome::Decisive {
+            winner: Color::Black,
+        };
+        for _ in 0..3 {
+            learning.record(&position, &e4, black_wins);
+            learning.record(&position, &d4, Outcome::Draw);
+        }
+        learning.save().unwrap();
+
+        let learning = Learning::open(&path).unwrap();
+        assert_eq!(
+            learning.get(&position, &e4),
+            Some(LearnedResult {
+                wins: 0,
+                draws: 0,
+                losses: 3
+            })
+        );
+        learning.adjust(&position, &mut moves);
+        assert_eq!(moves[0].1, 4);
+        assert_eq!(moves[1].1, 10);
+
+        fs::write(&path, "1 e2e4 1 2\n").unwrap();
+        assert!(matches!(
+            Learning::open(&path),
+            Err(BookError::Learning(_, 1))
+        ));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_choose() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let moves = uci_moves(&[("d2d4", 5), ("e2e4", 10), ("c2c4", 9), ("g1f3", 0)]);
+
+        for _ in 0..20 {
+            let best = choose(moves.clone(), Selection::Best, 100, &mut rng);
+            assert_eq!(to_uci(best), "e2e4");
+
+            // Only moves with at least 80% of the best weight.
+            let m = to_uci(choose(moves.clone(), Selection::Uniform, 20, &mut rng));
+            assert!(m == "e2e4" || m == "c2c4");
+
+            let m = to_uci(choose(moves.clone(), Selection::Weighted, 0, &mut rng));
+            assert_eq!(m, "e2e4");
+
+            let m = to_uci(choose(moves.clone(), Selection::Weighted, 100, &mut rng));
+            assert_ne!(m, "g1f3");
+        }
+
+        assert!(choose(Vec::new(), Selection::Best, 100, &mut rng).is_none());
+        assert!(choose(
+            uci_moves(&[("e2e4", 0)]),
+            Selection::Weighted,
+            100,
+            &mut rng
+        )
+        .is_none());
+        assert_eq!("Best".parse(), Ok(Selection::Best));
+    }
+}
@@ -1,24 +1,26 @@
-use crate::search::{NEG_INF, POS_INF};
-use shakmaty::{Board, Chess, Color, Outcome, Position};
+use crate::search::{SearchPosition, NEG_INF, POS_INF};
+use shakmaty::variant::Variant;
+use shakmaty::{Board, ByRole, Color, Outcome};
 
 #[inline]
-fn count_pieces(board: &Board) -> i16 {
-    let white_material = board.material_side(Color::White);
-    let black_material = board.material_side(Color::Black);
+fn material_value(material: &ByRole<u8>) -> i16 {
+    material.pawn as i16 * 100
+        + material.knight as i16 * 300
+        + material.bishop as i16 * 300
+        + material.rook as i16 * 500
+        + material.queen as i16 * 900
+}
 
-    white_material.pawn as i16 * 100
-        + white_material.knight as i16 * 300
-        + white_material.bishop as i16 * 300
-        + white_material.rook as i16 * 500
-        + white_material.queen as i16 * 900
-        - black_material.pawn as i16 * 100
-        - black_material.knight as i16 * 300
-        - black_material.bishop as i16 * 300
-        - black_material.rook as i16 * 500
-        - black_material.queen as i16 * 900
+#[inline]
+fn count_pieces(board: &Board) -> i16 {
+    material_value(&board.material_side(Color::White))
+        - material_value(&board.material_side(Color::Black))
 }
 
-pub fn evaluate(position: &Chess) -> i16 {
+/// Evaluation from the point of view of the side to move. Won and lost
+/// positions are scored by the rules of the variant, so reaching the centre
+/// in King of the Hill or giving the third check is a win like a checkmate.
+pub fn evaluate<P: SearchPosition>(position: &P) -> i16 {
     let side = position.turn();
     let board = position.board();
     let evaluation = match position.outcome() {
@@ -30,7 +32,15 @@ pub fn evaluate(position: &Chess) -> i16 {
                 NEG_INF
             }
         }
-        None => count_pieces(board),
+        // Losing all pieces wins in Antichess.
+        None if position.variant() == Variant::Antichess => -count_pieces(board),
+        None => {
+            // Pieces in hand in Crazyhouse.
+            let pockets = position.pockets().map_or(0, |pockets| {
+                material_value(&pockets.white) - material_value(&pockets.black)
+            });
+            count_pieces(board) + pockets
+        }
     };
     match side {
         Color::Black => -evaluation,
@@ -0,0 +1,143 @@
+// Game state shared by the UCI and XBoard front-ends: the engine, the position
+// being played and the positions before it.
+
+use crate::search::{Engine, Limits, SearchResult};
+use crate::ucicommand::{GoParams, UciError};
+use crate::ucioptions;
+use shakmaty::variant::{Variant, VariantPosition};
+use shakmaty::{fen::Fen, uci::Uci, Color, Position};
+use std::io;
+
+const LATENCY_MS: u64 = 100;
+const DEFAULT_DEPTH: u8 = 20;
+
+pub struct Game {
+    pub engine: Engine,
+    position: VariantPosition,
+    /// Positions before each move played, for `undo`.
+    history: Vec<VariantPosition>,
+}
+
+impl Game {
+    pub fn new() -> Game {
+        let mut engine = Engine::new();
+        ucioptions::apply_defaults(&mut engine);
+        Game {
+            engine,
+            position: VariantPosition::new(Variant::Chess),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn position(&self) -> &VariantPosition {
+        &self.position
+    }
+
+    /// Starts a game from the start position of the engine's variant.
+    pub fn new_game(&mut self) {
+        self.position = VariantPosition::new(self.engine.variant());
+        self.history.clear();
+        self.engine.new_game();
+    }
+
+    /// The position is left unchanged if the FEN or any of the moves is
+    /// illegal.
+    pub fn set_position(&mut self, fen: Option<&Fen>, moves: &[Uci]) -> Result<(), UciError> {
+        let variant = self.engine.variant();
+        let mut position = match fen {
+            None => VariantPosition::new(variant),
+            // Only three-check keeps count of the checks.
+            Some(fen) if fen.0.remaining_checks.is_some() && variant != Variant::ThreeCheck => {
+                return Err(UciError::IllegalPosition(fen.to_string()));
+            }
+            Some(fen) => VariantPosition::from_setup(
+                variant,
+                fen.clone().into(),
+                self.engine.castling_mode(),
+            )
+            .map_err(|_| UciError::IllegalPosition(fen.to_string()))?,
+        };
+        let mut history = Vec::new();
+        for uci in moves {
+            let m = uci
+                .to_move(&position)
+                .map_err(|_| UciError::IllegalMove(uci.to_string()))?;
+            history.push(position.clone());
+            position.play_unchecked(&m);
+        }
+        self.position = position;
+        self.history = history;
+        Ok(())
+    }
+
+    pub fn play(&mut self, uci: &Uci) -> Result<(), UciError> {
+        let m = uci
+            .to_move(&self.position)
+            .map_err(|_| UciError::IllegalMove(uci.to_string()))?;
+        self.history.push(self.position.clone());
+        self.position.play_unchecked(&m);
+        Ok(())
+    }
+
+    /// Takes back the last move. Returns false if there is none.
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some(position) => {
+                self.position = position;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Searches the current position within the limits of `params`, plays
+    /// the best move and returns it with its evaluation. The game must not be
+    /// over.
+    pub fn think(&mut self, params: &GoParams) -> (Uci, i16) {
+        let result = self.analyze(params);
+        self.history.push(self.position.clone());
+        self.position.play_unchecked(&result.best_move);
+        (result.uci, result.score)
+    }
+
+    /// Searches the current position like `think` without playing the move.
+    pub fn analyze(&mut self, params: &GoParams) -> SearchResult {
+        let depth = params
+            .depth
+            .map_or(DEFAULT_DEPTH, |depth| depth.min(255) as u8);
+        let limits = Limits {
+            time: Some(move_time(params, self.position.turn())),
+            depth: Some(depth),
+            nodes: params.nodes,
+        };
+        self.engine.search(&self.position.clone(), &limits)
+    }
+
+    /// Lets the engine learn from its book moves once the game has ended.
+    pub fn check_game_over(&mut self) -> io::Result<()> {
+        match self.position.outcome() {
+            Some(outcome) => self.engine.game_over(outcome),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for Game {
+    fn default() -> Game {
+        Game::new()
+    }
+}
+
+/// Time to spend on the move in milliseconds.
+pub fn move_time(params: &GoParams, turn: Color) -> u64 {
+    if params.infinite {
+        return u64::MAX;
+    }
+    if let Some(movetime) = params.movetime.filter(|t| *t != 0) {
+        return movetime;
+    }
+    let (time, inc) = turn.fold_wb((params.wtime, params.winc), (params.btime, params.binc));
+    (time.unwrap_or(1000) / 20)
+        .saturating_add(inc.unwrap_or(1000))
+        .saturating_sub(LATENCY_MS)
+}
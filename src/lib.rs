@@ -0,0 +1,51 @@
+//! The minikalle chess engine as a library.
+//!
+//! [`Engine`] searches positions of standard chess or of any variant
+//! supported by shakmaty, within the [`Limits`] of one search. Progress is
+//! reported as [`SearchInfo`] through a [`Reporter`], the book and its
+//! learning file are configured on the engine and read with [`book`], and
+//! [`evaluate`] is the handcrafted static evaluation. The UCI and XBoard
+//! front-ends of the `minikalle` binary are [`uciprotocol`] and [`xboard`].
+//!
+//! ```
+//! use minikalle::{Engine, Limits, SearchInfo};
+//! use shakmaty::Chess;
+//!
+//! let mut engine = Engine::new();
+//! engine.set_book(false);
+//! engine.set_reporter(Some(Box::new(|info: &SearchInfo| {
+//!     eprintln!("{}", info.to_uci());
+//! })));
+//!
+//! let limits = Limits {
+//!     depth: Some(3),
+//!     ..Limits::default()
+//! };
+//! let result = engine.search(&Chess::new(), &limits);
+//! println!("{} {}", result.uci, result.score);
+//! ```
+
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
+pub mod benchmark;
+pub mod book;
+pub mod evaluation;
+mod game;
+mod neural_eval;
+pub mod perft;
+pub mod pgn;
+pub mod polyglot;
+pub mod search;
+pub mod searchinfo;
+mod skill;
+pub mod trainingdata;
+mod transpositiontable;
+mod ucicommand;
+mod ucioptions;
+pub mod uciprotocol;
+pub mod xboard;
+
+pub use book::{Book, BookError, Selection};
+pub use evaluation::evaluate;
+pub use search::{Engine, Limits, Reporter, SearchPosition, SearchResult};
+pub use searchinfo::{Bound, Score, SearchInfo};
@@ -1,22 +1,33 @@
-#![feature(test, slice_swap_unchecked, const_trait_impl, portable_simd)]
-
+use minikalle::{benchmark, uciprotocol, xboard};
 use std::env::args;
 
-mod benchmark;
-mod evaluation;
-mod neural_eval;
-mod openings;
-mod search;
-mod transpositiontable;
-mod uciprotocol;
-
 fn main() {
     let mut args = args();
     let mut uci = uciprotocol::UciProtocol::new();
     let mode = args.nth(1).unwrap_or("".to_string());
     match mode.as_str() {
         "demo" => uci.demo(),
-        "benchmark" => benchmark::benchmark(args.last().unwrap_or("".to_string()).to_string()),
+        "perft" => {
+            // minikalle perft <depth> [fen]
+            let depth = args.next().unwrap_or("5".to_string());
+            let fen: Vec<String> = args.collect();
+            if fen.is_empty() {
+                uci.put(&"position startpos".to_string());
+            } else {
+                uci.put(&format!("position fen {}", fen.join(" ")));
+            }
+            uci.put(&format!("perft {depth}"));
+        }
+        "xboard" => xboard::XboardProtocol::new().start(),
+        "bench" => {
+            // minikalle bench [depth]
+            let depth = args
+                .next()
+                .and_then(|depth| depth.parse().ok())
+                .unwrap_or(benchmark::BENCH_DEPTH);
+            benchmark::print_bench(depth);
+        }
+        "benchmark" => benchmark::benchmark(args.next_back().unwrap_or("".to_string()).to_string()),
         _ => uci.start(),
     };
 }
@@ -1,8 +1,29 @@
 use shakmaty::{Chess, Color, Position};
-use std::simd::{i16x8, num::SimdInt};
+#[cfg(feature = "simd")]
+use std::simd::{i16x8, i32x8, num::SimdInt};
+
+// Quantization scheme
+//
+// Activations are i16 fixed point numbers where `ACTIVATION_SCALE` is 1.0.
+// The inputs are one-hot piece-square features, so they are either 0 or 1.0.
+//
+// The weights and biases of layer `l` are stored with scale `WEIGHT_SCALES[l]`,
+// weights in row-major `[output][input]` order as exported from pytorch by
+// `neural_evaluator/export_weights.py`.
+//
+// A neuron accumulates `sum(a * w)` in i32 at scale
+// `ACTIVATION_SCALE * WEIGHT_SCALES[l]`. The bias is brought to the same scale
+// by multiplying it with `ACTIVATION_SCALE`. Dividing the accumulator by
+// `WEIGHT_SCALES[l]` gives the output back at activation scale, which is passed
+// through a clipped ReLU, clamp(x, 0.0, 1.0), on the hidden layers and left as is
+// on the output layer.
 
 const LANES: usize = 8;
-const FIXED_POINT: i16 = 32;
+const ACTIVATION_SCALE: i32 = 127;
+// Must match `SCALES` in `neural_evaluator/export_weights.py`.
+const WEIGHT_SCALES: [i32; 5] = [32, 32, 32, 32, 32];
+// Centipawns per 1.0 of network output.
+const OUTPUT_SCALE: i32 = 32;
 
 const L0: usize = 768;
 const L1: usize = 32;
@@ -11,11 +32,11 @@ const L3: usize = 8;
 const L4: usize = 8;
 const L5: usize = 1;
 
-const W0: [i16; L0 * L1] = include!("model/W0.in");
+static W0: [i16; L0 * L1] = include!("model/W0.in");
 const W1: [i16; L1 * L2] = include!("model/W1.in");
 const W2: [i16; L2 * L3] = include!("model/W2.in");
 const W3: [i16; L3 * L4] = include!("model/W3.in");
-const W4: [i16; L4] = include!("model/W4.in");
+const W4: [i16; L4 * L5] = include!("model/W4.in");
 
 const B0: [i16; L1] = include!("model/B0.in");
 const B1: [i16; L2] = include!("model/B1.in");
@@ -23,57 +44,78 @@ const B2: [i16; L3] = include!("model/B2.in");
 const B3: [i16; L4] = include!("model/B3.in");
 const B4: [i16; L5] = include!("model/B4.in");
 
-macro_rules! apply_layer {
-    ($h0:expr, $h1:expr, $l0:expr, $l1:expr, $b:expr, $w:expr) => {
-        let fixed_point = i16x8::splat(FIXED_POINT);
-
-        for e in $h1.iter_mut() {
-            let mut zs = [0; $l1 / LANES];
-            for i in 0..($l1 / LANES) {
-                let a = i16x8::from_slice(&$h0[i * LANES..i * LANES + LANES]);
-                let b = i16x8::from_slice(&$b[i * LANES..i * LANES + LANES]);
-                let w = i16x8::from_slice(&$w[i * LANES..i * LANES + LANES]);
-                zs[i] = (a * w / fixed_point + b).reduce_sum();
-            }
-            *e = zs.iter().map(|x| (*x).max(0)).sum();
+#[cfg(feature = "simd")]
+#[inline]
+fn dot(a: &[i16], w: &[i16]) -> i32 {
+    let mut acc = i32x8::splat(0);
+    for (a, w) in a.chunks_exact(LANES).zip(w.chunks_exact(LANES)) {
+        let a: i32x8 = i16x8::from_slice(a).cast();
+        let w: i32x8 = i16x8::from_slice(w).cast();
+        acc += a * w;
+    }
+    acc.reduce_sum()
+}
+
+#[cfg(not(feature = "simd"))]
+#[inline]
+fn dot(a: &[i16], w: &[i16]) -> i32 {
+    let mut acc = [0i32; LANES];
+    for (a, w) in a.chunks_exact(LANES).zip(w.chunks_exact(LANES)) {
+        for i in 0..LANES {
+            acc[i] += a[i] as i32 * w[i] as i32;
         }
-    };
+    }
+    acc.iter().sum()
 }
 
-fn feed_forward(input: &[i16; L0]) -> i16 {
-    // Layer 0
+/// Computes the layer outputs at activation scale, before the activation function.
+#[inline]
+fn dense<'a>(
+    input: &'a [i16],
+    weights: &'a [i16],
+    biases: &'a [i16],
+    weight_scale: i32,
+) -> impl Iterator<Item = i32> + 'a {
+    debug_assert_eq!(input.len() % LANES, 0);
+    debug_assert_eq!(weights.len(), input.len() * biases.len());
+
+    weights
+        .chunks_exact(input.len())
+        .zip(biases)
+        .map(move |(row, b)| (dot(input, row) + *b as i32 * ACTIVATION_SCALE) / weight_scale)
+}
 
-    let mut h0 = [0; L1];
-    apply_layer!(input, h0, L0, L1, B0, W0);
+#[inline]
+fn hidden_layer(
+    input: &[i16],
+    weights: &[i16],
+    biases: &[i16],
+    weight_scale: i32,
+    output: &mut [i16],
+) {
+    for (h, z) in output
+        .iter_mut()
+        .zip(dense(input, weights, biases, weight_scale))
+    {
+        *h = z.clamp(0, ACTIVATION_SCALE) as i16;
+    }
+}
 
-    // Layer 1
+/// Network output at activation scale.
+fn feed_forward(input: &[i16; L0]) -> i32 {
+    let mut h0 = [0; L1];
+    hidden_layer(input, &W0, &B0, WEIGHT_SCALES[0], &mut h0);
 
     let mut h1 = [0; L2];
-    apply_layer!(h0, h1, L1, L2, B1, W1);
-
-    // Layer 2
+    hidden_layer(&h0, &W1, &B1, WEIGHT_SCALES[1], &mut h1);
 
     let mut h2 = [0; L3];
-    apply_layer!(h1, h2, L2, L3, B2, W2);
-
-    // Layer 3
+    hidden_layer(&h1, &W2, &B2, WEIGHT_SCALES[2], &mut h2);
 
     let mut h3 = [0; L4];
-    apply_layer!(h2, h3, L3, L3, B3, W3);
-
-    // Output Layer
-
-    let fixed_point = i16x8::splat(FIXED_POINT);
-
-    let mut zs = [0; L5 / LANES];
-    for i in 0..(L5 / LANES) {
-        let a = i16x8::from_slice(&h3[i * LANES..i * LANES + LANES]);
-        let b = i16x8::from_slice(&B4[i * LANES..i * LANES + LANES]);
-        let w = i16x8::from_slice(&W4[i * LANES..i * LANES + LANES]);
-        zs[i] = (a * w / fixed_point + b).reduce_sum();
-    }
+    hidden_layer(&h2, &W3, &B3, WEIGHT_SCALES[3], &mut h3);
 
-    zs.iter().map(|x| (*x).max(0)).sum()
+    dense(&h3, &W4, &B4, WEIGHT_SCALES[4]).sum()
 }
 
 fn serialize(position: &Chess) -> [i16; L0] {
@@ -98,7 +140,7 @@ fn serialize(position: &Chess) -> [i16; L0] {
 
             for sq in bb {
                 let sq = sq as usize;
-                result[index + sq] = FIXED_POINT;
+                result[index + sq] = ACTIVATION_SCALE as i16;
             }
             index += 64;
         }
@@ -109,9 +151,92 @@ fn serialize(position: &Chess) -> [i16; L0] {
 
 pub fn predict(position: &Chess) -> i16 {
     let input = serialize(position);
-    feed_forward(&input)
-        * match position.turn() {
-            Color::White => 1,
-            Color::Black => -1,
+    let evaluation = (feed_forward(&input) * OUTPUT_SCALE / ACTIVATION_SCALE) as i16;
+    match position.turn() {
+        Color::White => evaluation,
+        Color::Black => -evaluation,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    const TOLERANCE: f32 = 0.05;
+
+    fn dequantize(values: &[i16], scale: i32) -> Vec<f32> {
+        values.iter().map(|x| *x as f32 / scale as f32).collect()
+    }
+
+    fn dense_f32(input: &[f32], weights: &[f32], biases: &[f32]) -> Vec<f32> {
+        weights
+            .chunks_exact(input.len())
+            .zip(biases)
+            .map(|(row, b)| row.iter().zip(input).map(|(w, a)| w * a).sum::<f32>() + b)
+            .collect()
+    }
+
+    fn clipped_relu_f32(xs: Vec<f32>) -> Vec<f32> {
+        xs.into_iter().map(|x| x.clamp(0.0, 1.0)).collect()
+    }
+
+    /// Floating point reference of `feed_forward`.
+    fn feed_forward_f32(input: &[f32]) -> f32 {
+        let layers: [(&[i16], &[i16]); 5] =
+            [(&W0, &B0), (&W1, &B1), (&W2, &B2), (&W3, &B3), (&W4, &B4)];
+
+        let mut h = input.to_vec();
+        for (l, (weights, biases)) in layers.iter().enumerate() {
+            let weights = dequantize(weights, WEIGHT_SCALES[l]);
+            let biases = dequantize(biases, WEIGHT_SCALES[l]);
+            h = dense_f32(&h, &weights, &biases);
+            if l < layers.len() - 1 {
+                h = clipped_relu_f32(h);
+            }
         }
+
+        h[0]
+    }
+
+    #[test]
+    fn test_quantized_matches_reference() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..20 {
+            let mut position = Chess::new();
+
+            for _ in 0..60 {
+                let input = serialize(&position);
+                let reference = feed_forward_f32(&dequantize(&input, ACTIVATION_SCALE));
+                let quantized = feed_forward(&input) as f32 / ACTIVATION_SCALE as f32;
+
+                assert!(
+                    (quantized - reference).abs() < TOLERANCE,
+                    "quantized {quantized} reference {reference}"
+                );
+
+                let moves = position.legal_moves();
+                let Some(m) = moves.choose(&mut rng) else {
+                    break;
+                };
+                position.play_unchecked(m);
+            }
+        }
+    }
+
+    #[test]
+    fn test_accumulator_does_not_overflow() {
+        // Exported weights are clamped to the i8 range.
+        let input = [ACTIVATION_SCALE as i16; L0];
+        let weights = [i8::MIN as i16; L0];
+
+        assert_eq!(
+            dot(&input, &weights),
+            L0 as i32 * ACTIVATION_SCALE * i8::MIN as i32
+        );
+    }
 }
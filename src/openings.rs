@@ -1,2 +0,0 @@
-#[rustfmt::skip]
-pub const OPENINGS: &str = r#"{"5060803636482931868":["e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","c2c4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","d2d4","e2e4","e2e4","e2e4","d2d4","d2d4","g1f3","e2e4","g1f3","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","d2d4","e2e4","e2e4","g1f3","e2e4","e2e4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","e2e4","d2d4","d2d4","d2d4","e2e4","e2e4","d2d4","d2d4","d2d4","d2d4","e2e4","e2e4","e2e4","d2d4","e2e4","e2e4","e2e4","e2e4","e2e4","g1f3","e2e4","e2e4","d2d4","d2d4","g1f3","e2e4","d2d4","d2d4","e2e4","d2d4","e2e4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","g1f3","e2e4","c2c4","c2c4","g1f3","g1f3","d2d4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","d2d4","b2b3","e2e3","e2e3","d2d4","d2d4","d2d4","e2e3","e2e4","e2e4","e2e4","e2e4","d2d4","c2c4","d2d4","c2c4","d2d4","e2e4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","c2c4","c2c4","c2c4","c2c4","d2d4","c2c4","c2c4","d2d4","c2c4","d2d4","d2d4","d2d4","d2d4","c2c4","d2d4","c2c4","c2c4","c2c4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","a2a3","d2d4","e2e4","e2e4","e2e4","b1c3","e2e4","g1f3","c2c4","e2e4","d2d4","g2g3","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","g1f3","g1f3","g1f3","g1f3","d2d4","e2e4","c2c4","g1f3","d2d4","c2c4","c2c4","c2c4","d2d4","c2c4","g1f3","g1f3","c2c4","d2d4","d2d4","g1f3","c2c4","d2d4","c2c4","d2d4","c2c4","a2a4","e2e4","b2b3","d2d4","e2e4","h2h4","b2b3","b2b3","h2h4","e2e3","e2e4","f2f4","e2e3","d2d3","b2b3","e2e3","h2h4","a2a4","g1f3","d2d4","d2d4","d2d4","g1f3","c2c4","e2e4","e2e4","d2d4","g1f3","d2d4","g1f3","g1f3","e2e4","g1f3","e2e4","g1f3","e2e4","e2e4","c2c4","e2e4","e2e4","d2d4","d2d4","d2d4","d2d4","g1f3","e2e4","g1f3","g1f3","e2e4","g1f3","d2d4","d2d4","e2e4","e2e4","g1f3","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","b2b3","b2b3","e2e4","b2b3","e2e4","e2e4","b2b3","d2d4","b2b3","b2b3","b2b3","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","e2e4","d2d4","e2e4","e2e4","d2d4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","d2d4","g1f3","g1f3","e2e4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","c2c4","c2c4","c2c4","c2c4","d2d4","d2d4","c2c4","c2c4","c2c4","c2c4","d2d4","c2c4","c2c4","e2e4","d2d4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","d2d4","d2d4","e2e4","d2d4","d2d4","d2d4","d2d4","e2e4","d2d4","d2d4","d2d4","d2d4","e2e4","g1f3","g1f3","d2d4","e2e4","c2c4","b2b4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","c2c4","d2d4","e2e4","c2c4","c2c4","c2c4","c2c4","e2e4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","e2e4","e2e4","e2e4","d2d4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","g1f3","g1f3","e2e4","d2d4","d2d4","d2d4","d2d4","d2d4","g1f3","d2d4","g1f3","c2c4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","e2e4","d2d4","d2d4","d2d4","c2c4","d2d4","d2d4","d2d4","d2d4","d2d4","g1f3","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","e2e4","d2d4","e2e4","c2c4","d2d4","d2d4","d2d4","c2c4","d2d4","g1f3","d2d4","d2d4","d2d4","e2e4","e2e4","d2d4","c2c4","e2e4","e2e4","e2e3","e2e4","e2e4","d2d4","e2e4","c2c4","d2d4","d2d4","e2e4","d2d4","e2e4","g1f3","g1f3","e2e4","e2e4","c2c4","e2e4","e2e4","e2e4","d2d4","d2d4","g1f3","g1f3","e2e4","e2e4","e2e4","g1f3","e2e4","e2e4","g1f3","g1f3","d2d4","e2e4","d2d4","c2c4","c2c4","d2d4","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","c2c4","c2c4","d2d4","g1f3","g1f3","d2d4","g1f3","d2d4","c2c4","d2d4","d2d4","c2c4","c2c4","d2d4","d2d4","d2d4","g1f3","d2d4","d2d4","d2d4","c2c4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","c2c4","d2d4","d2d4","c2c4","d2d4","e2e4","g1f3","d2d4","e2e4","d2d4","e2e4","e2e4","e2e4","d2d4","d2d4","e2e4","d2d4","e2e4","e2e4","e2e4","e2e4","e2e4","d2d4","e2e4","d2d4","e2e4","e2e4","d2d4","e2e4","d2d4","d2d4","d2d4","e2e4","e2e4","d2d4","e2e4","e2e4","d2d4","e2e4","e2e4","c2c4","d2d4","d2d4","d2d4","e2e4","d2d4","e2e4","e2e4","d2d4","e2e4","d2d4","e2e4","e2e4","d2d4","d2d4","d2d4","d2d4","g1f3","d2d4","e2e4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","e2e4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","e2e4","a2a3","e2e4","g2g3","g2g3","g2g3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","d2d4","e2e4","g1f3","g1f3","g1f3","g1f3","e2e4","g1f3","g1f3","g1f3","e2e4","d2d4","g1f3","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4","g1f3","e2e4","e2e4","e2e4","c2c4","e2e4","e2e4","e2e4","e2e4","e2e4","g1f3","d2d4","e2e4","e2e4","e2e4","d2d4","g1f3","d2d4","g1f3","e2e4","g1f3","e2e4","d2d4","c2c4","d2d4","d2d4","c2c4","d2d4","c2c4","e2e4","c2c4","e2e4","d2d4","e2e4","e2e4","b2b3","e2e4","d2d4","d2d4","d2d4","c2c4","e2e4","e2e4","e2e4","d2d4","d2d4","e2e4","c2c4","e2e4","e2e4","c2c4","d2d4","c2c4","d2d4","c2c4","c2c4","d2d4","c2c4","d2d4","c2c4","c2c4","d2d4","c2c4","c2c4","d2d4","c2c4","g1f3","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","d2d4","g1f3","d2d4","d2d4","d2d4","d2d4","e2e4","e2e4","f2f4","d2d4","d2d4","e2e4","e2e4","e2e4","d2d4","c2c4","c2c4","d2d4","e2e4","e2e4","d2d4","d2d4","g1f3","d2d4","e2e4","d2d4","e2e4"],"9384546495678726550":["e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","c7c5","c7c5","c7c5","c7c5","c7c5","c7c5","c7c5","e7e5","e7e5","e7e5","c7c5","e7e5","c7c5","e7e5","c7c5","e7e5","c7c5","e7e5","e7e6","c7c6","c7c5","e7e5","e7e5","c7c5","c7c5","e7e5","e7e5","e7e5","e7e5","c7c5","c7c5","e7e5","e7e6","e7e6","d7d6","c7c5","c7c5","c7c5","c7c6","e7e5","c7c5","g7g6","e7e5","e7e5","e7e6","e7e6","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","c7c6","e7e5","e7e5","e7e5","c7c6","c7c5","c7c5","c7c5","e7e5","e7e5","e7e6","e7e5","e7e5","e7e5","g7g6","e7e6","e7e5","c7c5","c7c5","e7e6","e7e6","c7c5","c7c5","g7g6","d7d5","c7c5","d7d5","c7c5","e7e5","c7c6","e7e5","c7c5","c7c5","e7e5","e7e6","e7e6","c7c6","e7e5","c7c6","c7c5","c7c5","e7e5","e7e5","c7c5","c7c5","c7c5","c7c5","e7e5","e7e5","c7c6","c7c6","e7e5","e7e6","c7c5","c7c5","e7e6","e7e5","c7c5","c7c5","c7c5","c7c5","c7c6","d7d6","e7e5","c7c5","d7d5","e7e6","c7c5","c7c5","c7c5","c7c5","c7c5","e7e5","e7e5","g8f6","c7c5","c7c5","c7c6","d7d5","d7d6","c7c5","c7c5","c7c6","c7c5","e7e6","e7e5","e7e5","d7d6","c7c5","a7a6","c7c6","e7e5","e7e5","c7c5","e7e5","e7e5","e7e5","e7e5","c7c5","e7e5","c7c5","e7e6","e7e5","e7e5","e7e5","c7c6","c7c5","c7c5","e7e5","c7c5","c7c6","e7e5","e7e5","e7e5","c7c6","e7e5","e7e6","e7e5","c7c5","c7c5","c7c6","g7g6","b8c6","c7c6","e7e5","c7c6","e7e6","e7e5","c7c5","e7e5","e7e6","c7c6","c7c6","e7e5","c7c5","c7c5","e7e6","c7c5","c7c5","e7e6","c7c6","c7c6","c7c5","c7c5","e7e5","c7c5","c7c5","c7c5","e7e5","c7c6","c7c5","c7c5","c7c5","d7d6","d7d5","e7e5","d7d6","g8f6","e7e5","d7d5","e7e5","e7e6","e7e5","e7e5","e7e5","d7d6","b8c6","c7c5","e7e5","e7e5","e7e5","c7c5","e7e5","e7e5","e7e5","c7c5","d7d5","d7d5","e7e5","e7e5","g7g6","c7c5","e7e5","d7d5","e7e5","c7c5","e7e5","e7e5","e7e6","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e5","e7e6","c7c5","c7c5","c7c5","c7c5","c7c5","e7e5","c7c5","c7c6","c7c5","g8f6","c7c6","e7e5","e7e5","c7c5","e7e6","c7c5","e7e5","e7e5","c7c5","c7c5","c7c5","c7c5","c7c5","c7c5","c7c5","e7e6","e7e6","c7c5","c7c5","d7d6","e7e5","c7c5","e7e6","c7c5","e7e5","g8f6","e7e6","c7c5","g7g6","g7g6","e7e5","e7e5","e7e6","e7e6","c7c5","e7e5","e7e6","c7c6","e7e5","e7e5","c7c5","e7e5","e7e5"],"595762792459712928":["b1c3","f2f4","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","d2d4","g1f3","g1f3","g1f3","d2d4","f1c4","g1f3","g1f3","g1f3","f2f4","g1f3","g1f3","g1f3","d2d4","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","d2d4","d2d4","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","b1c3","g1f3","g1f3","f2f4","g1f3","f2f4","g1f3","f2f4","f1c4","f2f4","g1f3","g1f3","f2f4","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","f2f4","f2f4","f2f4","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3"],"17569540196579818361":["g8f6","b8c6"],"12123056109416905455":["f1c4"],"14667673565511265353":["f6e4"],"8518916294944754522":["d1h5"],"15435144453556612550":["e4d6"],"8235992663877359348":["c4b3"],"13495053409665173008":["f8e7"],"17677453271937418281":["e5f4","e5f4","f8c5","e5f4","f8c5","e5f4","e5f4","e5f4","e5f4","e5f4"],"2078644914092671997":["f1c4","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3"],"9181538511791674715":["d7d5"],"18014753333679299453":["c4d5"],"13293492897651092791":["g8f6"],"16399239767114493089":["b1c3"],"1730179457260662392":["f8b4"],"15213300192948443293":["b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","g8f6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","g8f6","b8c6","b8c6","g8f6","b8c6","b8c6","g8f6","b8c6","g8f6","b8c6","g8f6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","g8f6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","g8f6","b8c6","g8f6","b8c6","g8f6","b8c6","b8c6","b8c6","b8c6","g8f6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","d7d6","b8c6","b8c6","g8f6","g8f6","b8c6","b8c6","g8f6","b8c6","b8c6","b8c6","b8c6","g8f6","b8c6","g8f6","b8c6","b8c6","b8c6","b8c6","g8f6"],"8704797333742910878":["f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1c4","f1b5","f1c4","f1c4","f1c4","f1c4","f1c4","f1b5","f1b5","f1b5","f1c4","f1c4","f1b5","b1c3","b1c3","f1b5","f1b5","f1b5","f1b5","c2c3","c2c3","c2c3","f1b5","f1b5","f1b5","f1c4","f1b5","f1b5","f1b5","f1c4","f1b5","f1b5","f1b5","f1c4","f1b5","f1b5","f1b5","f1b5","f1b5","f1c4","f1c4","f1b5","f1c4","f1c4","f1b5","f1c4","b1c3","f1b5","f1b5","f1b5","d2d4","f1b5","f1c4","f1c4","f1c4","f1c4","f1c4","f1c4","f1c4","f1c4","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","b1c3","f1b5","d2d4","f1b5","d2d4","f1b5"],"5409798013178080797":["a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","g8f6","a7a6","g8f6","a7a6","a7a6","g8f6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","f8c5","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","g8f6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","g8f6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","g8e7","a7a6","g8f6","a7a6","a7a6","g8f6","a7a6","a7a6","a7a6","a7a6","g8e7","g8f6","g8f6","a7a6"],"11089136971253487297":["b5a4","b5a4","b5a4","b5a4","b5c6","b5c6","b5a4","b5c6","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5c6","b5c6","b5c6","b5c6","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4","b5a4"],"12170917594475360235":["g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8e7","d7d6","d7d6","g8f6","d7d6","g8f6","g8f6","d7d6","g8f6","g8f6","g8f6","g8f6","g8f6","d7d6","g8f6","g8f6","g8f6","b7b5","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6"],"17512807785136074365":["e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","b1c3","e1g1","e1g1","e1g1","b1c3","e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","b1c3","e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","d2d3","e1g1","e1g1"],"10195171887328851309":["f8e7","f8e7","f8e7","f8e7","f8e7","f8e7","f8e7","f8e7","f8e7","f8e7","f8e7","f8e7","f6e4","d7d6","f8e7","f8e7","b7b5","f6e4","f8e7","b7b5","f8e7","f6e4","f6e4","f8e7","f8e7","f8e7","f8e7","f8e7","f6e4","f8e7","b7b5","b7b5","f8e7","f8e7"],"14468866809511659258":["d7c6","d7c6","d7c6","d7c6","d7c6","d7c6","d7c6"],"18310404510167817349":["e1g1","e1g1","e1g1","e1g1","e1g1","e1g1","e1g1"],"9254585899963942805":["f7f6","f7f6","f7f6","f8d6","c8g4","f8d6","c8g4"],"7227515431820872427":["g1f3","g1f3","b1c3","b1c3","g1f3","b1c3","b1c3","b1c3","b1c3","g1f3","b1c3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","b1c3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","d2d4","d2d4","d2d4","g1f3","a2a3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1e2","b1c3","b1c3","b1c3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3"],"13774723490369252310":["b8c6","b8c6","b8c6","b8c6","d7d6","d7d6","d7d6","d7d6","d7d6","d7d6","e7e6","b8c6","e7e6","b8c6","e7e6","b8c6","d7d6","d7d6","d7d6","b8c6","e7e6","b8c6","g7g6","d7d6","e7e6","d7d6","b8c6","d7d6","e7e6","d7d6","d7d6","e7e6","e7e6","e7e6","e7e6","d7d6","b8c6","d7d6","d7d6","e7e6","d7d6","b8c6","d7d6","d7d6","d7d6","b8c6","e7e6","b8c6","a7a6","d7d6","d7d6","d7d6","e7e6","d7d6","b8c6","d7d6","d7d6","e7e6","e7e6","b8c6","d7d6","d7d6","d7d6","d7d6","d7d6","d7d6","d7d6","d7d6","d7d6","b8c6","e7e6","d7d6","b8c6","b8c6","b8c6","b8c6","b8c6","e7e6","d7d6","e7e6","d7d6","d7d6"],"1496460521747091157":["f1b5","f1b5","d2d4","d2d4","f1b5","f1b5","f1b5","f1b5","d2d4","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","f1b5","b1c3","c2c3","f1b5","f1b5","d2d4"],"2817757228276456790":["g7g6","g7g6","g7g6","e7e6","e7e6","g7g6","g7g6","e7e5","g7g6","e7e6","e7e6","d8b6","e7e6","e7e6","g7g6","d7d6"],"14893118225238363933":["b5c6","b5c6","e1g1","b5c6","e1g1","e1g1","e1g1"],"11493686495759064870":["b7c6","b7c6","d7c6"],"6616255062661905164":["e1g1","e1g1"],"2712525803163508764":["f8g7","f8g7"],"14562399549841627035":["e7e6","g8f6","e7e5","g8f6","e7e6","g8f6","g8f6","g8f6","e7e5","c7c6","e7e6","g8f6","g8f6","e7e6","e7e5","g8f6","e7e6","e7e5","e7e5","e7e6","e7e6","b7b6","e7e5","e7e6","e7e5","c7c5","c7c6","e7e5","e7e6","c7c6","g8f6","e7e5","e7e6","g8f6","e7e6","c7c5","g8f6","g8f6","g7g6","g7g6","c7c5","e7e6","e7e6","e7e5","g8f6","c7c5","c7c6","e7e5","g8f6","g8f6","e7e6","e7e5","e7e5","g8f6","e7e6","e7e5","g8f6","e7e5","g8f6","g8f6","e7e5","e7e6","g8f6","g8f6","g7g6","b7b6","g8f6","e7e5","c7c5","e7e5","g8f6","c7c6","g8f6","e7e5","e7e5","g8f6","c7c6","e7e5","g7g6","g8f6","g7g6","g8f6","g8f6","c7c5","g7g6","c7c5","e7e5","c7c5","g8f6","f7f5","g7g6","e7e5","e7e6","e7e6","g8f6"],"13578347436102698953":["g1f3","b1c3","e2e4","e2e4","g2g3","g2g3","b1c3","g2g3","b1c3","b1c3","b1c3","b1c3","b1c3","g2g3","g2g3","g2g3","b1c3","d2d4"],"7425054778081322740":["g8f6"],"4387951957271177058":["g2g3","b1c3","g2g3","g2g3","b1c3","g2g3","d2d4","g2g3"],"1153933396359462746":["d7d5","d7d5","d7d5","d7d5","b7b6"],"10766341288315023740":["f1g2","f1g2","f1g2","f1g2","f1g2","f1g2"],"2349077072247171333":["f8e7","c7c6","c7c6","f8e7","f8e7","d5c4","f8e7","f8e7","d5d4","f8e7","d5c4"],"3771178413946373524":["e1g1","d2d4","d2d4","e1g1","e1g1","d2d4"],"5341429674127318660":["e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","c7c6"],"11518609555277006898":["b8c6","b8c6","e7e6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","g7g6","g7g6","b8c6","d7d6","b8c6","a7a6","b8c6","b8c6","b8c6"],"3762628565848649009":["g1e2","g1e2","g1e2","g1e2","g1e2","g1e2","f1b5","g1f3","g1f3","f2f4","g1e2","g2g3","g2g3"],"2347326972927828175":["e7e6","e7e5","d7d6","e7e5","e7e6","e7e6","d7d6"],"6261291288816840861":["g2g3","g2g3","g2g3","d2d4"],"8791633407373944997":["d7d5","d7d5","d7d5"],"18403970965197252227":["e4d5","e4d5","e4d5"],"15615950612082992261":["e6d5","e6d5","e6d5"],"12316053309892565241":["c3d5","c3d5"],"7709818515593291692":["g8e7","g8e7"],"6102794943546485777":["e2c3","e2c3"],"16477061025398265009":["e7d5","e7d5"],"15127961960427594647":["c5d4","c5d4","c5d4","c5d4"],"10299709760951077457":["f3d4","f3d4","f3d4","f3d4"],"16824241115991201919":["e7e6","e7e6","e7e6","g8f6"],"11460610026420291629":["d4b5","d4b5","b1c3","b1c3","b1c3","b1c3"],"7667007229414863483":["d7d6","d7d6"],"16838290975453763680":["g1e2"],"18233187729581888926":["b8c6"],"1813251973814036898":["d2d4","d2d3"],"15860790282053158112":["c5d4"],"9440216136437951782":["e2d4"],"3122745973977617355":["e7e6"],"10651502310957899231":["c5d4"],"14758022061130626073":["e2d4"],"7249489987118123764":["d7d6","a7a6","d8c7","d8c7","a7a6"],"1224207606016225163":["d2d3","e1g1","e1g1","d2d3","d2d3","d2d3","d2d3","d2d3","b1c3"],"13716136533792986207":["f8c5","d7d6","f8c5","f8c5","f8c5","f8c5"],"1388452126594694876":["c2c3","c2c3","b5c6","b5c6","b5c6"],"7322357399996592474":["e8g8","e8g8"],"17603279437685838276":["d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","b1c3","b1c3","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4"],"3566365796726406278":["d7d5","d7d5","d7d5","g8e7","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5"],"12976107337049617056":["b1d2","e4e5","b1c3","b1d2","b1c3","b1d2","e4d5","b1d2","b1d2","b1c3","b1c3","b1c3","b1c3","e4d5","b1c3","e4e5","e4e5","b1c3","b1d2","b1c3","e4d5","b1c3","b1c3","b1c3","b1d2","b1d2","e4d5","b1c3","b1c3","b1c3"],"17112232442528471696":["c7c5","d5e4","c7c5","a7a6","g8f6","g8f6","d5e4","d5e4"],"795744134644371949":["g1f3","g1f3"],"15019471657669652688":["c5d4","c5d4"],"10336143569114765590":["e4d5","f3d4"],"12154742182341335824":["d8d5"],"1172755318016414253":["d2d4","d2d4","b1c3","b1c3","g1f3","d2d4","d2d4","d2d4","d2d4","d2d4","b1c3","d2d4","d2d4","d2d4","d2d4","g1f3","d2d4","d2d4","d2d4","c2c4","d2d4","d2d4","d2d4","d2d4","g1f3","d2d4","d2d4"],"15380735101731651439":["d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","g7g6","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","a7a6","d7d5","d7d5"],"5771779641808056649":["e4e5","e4d5","f2f3","e4e5","b1c3","b1c3","b1c3","e4e5","e4e5","e4e5","e4e5","e4e5","e4e5","e4d5","e4e5","e4e5","e4e5","b1c3","e4e5"],"3557964634786027309":["c6c5","c6c5","c8f5","c8f5","c8f5","c6c5","c6c5","c6c5","c6c5","c8f5","c8f5","c8f5"],"13672214985046133474":["d4c5","g1f3","g1f3","g1f3","g1f3","g1f3"],"14005726207701605774":["b8c6"],"7616586588474013837":["g1f3"],"12886876420799273392":["c8g4"],"9697147653288649403":["d2d4","d2d4","f1b5","d2d4","d2d4","f1b5","d2d4","f1b5","f1b5","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","b1c3","b1c3","d2d4","d2d4","d2d4","d2d4","f1b5","f1b5","g2g3","d2d4","d2d4","f1b5","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","f1b5","d2d4","d2d4","f1b5","d2d4"],"4874618571889659897":["c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4"],"2069586508330116671":["f3d4","f3d4","f3d4","f3d4","f3d4","f3d4","f3d4","f3d4","d1d4","d1d4","d1d4","f3d4","f3d4","f3d4","f3d4","f3d4","f3d4","f3d4","f3d4","f3d4","f3d4","f3d4","f3d4","d1d4","f3d4","f3d4","f3d4","f3d4"],"8875742382281503761":["g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6"],"2360673860543509895":["b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3"],"15804772648990046046":["a7a6","b8c6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","b8c6","b8c6","b8c6","b8c6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","a7a6","g7g6","a7a6"],"7964679028221047963":["f6e4","f6e4"],"15222016355013874568":["f1e1","f1e1"],"6308269509050464412":["e4d6","e4d6"],"13064346338305306936":["b8d7","b8d7","b8c6","c8d7","c8d7","b8d7","b8d7","b8d7","b8d7"],"9704864238580014324":["c2c3","c2c3","e1g1","e1g1","e1g1","d2d4"],"17326581435410420594":["g8f6","g8f6"],"12365291342543082212":["d1e2","d1e2"],"4439662837475445088":["a7a6","a7a6"],"9857899207991007499":["f3e5","f3e5","d2d4","b1c3","b1c3","f3e5","d2d4","d2d4","f3e5","f3e5","f3e5","f3e5","f3e5","d2d4","f3e5","f3e5","f3e5"],"3931009027415620669":["d7d6","d7d6","d7d6","d7d6","d7d6","d7d6","d7d6","d7d6","d7d6","d7d6","d7d6"],"1096390680622560592":["e5f3","e5f3","e5d3","e5f3","e5f3","e5f3","e5f3","e5f3","e5f3","e5f3","e5f3"],"15136587681141821478":["f6e4","f6e4","f6e4","f6e4","f6e4","f6e4","f6e4","f6e4","f6e4","f6e4"],"8050386736420009781":["d2d4","b1c3","b1c3","d2d4","b1c3","d2d4","f1d3","d2d4","d2d4","d2d4"],"12289645807193135735":["d6d5","d6d5","d6d5","d6d5","d6d5","d6d5"],"15379073794709689540":["c7c5","c7c5","c7c5"],"3682998419447062457":["c2c3","c2c3","c2c3"],"5027806176587780159":["b8c6","b8c6","b8c6"],"17161955898996176188":["g1f3","g1f3","g1f3"],"3841411053941445633":["d8b6","d8b6","d8b6"],"5729560030123667577":["b8c6","d5e4","g8f6","f8b4","g8f6","f8b4","g8f6","g8f6","f8b4","f8b4","g8f6","f8b4","a7a6","f8b4","g8f6"],"16460274694035969402":["g1f3"],"4542522793517642823":["g8f6"],"7270072710057801169":["f1d3"],"3596649036993623970":["c6b4"],"13512502236871723259":["d2d4","f1c4","d2d4","b1c3","d2d4","d2d4","d2d4","d2d4"],"9129678919776085433":["g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6"],"2692332816411812911":["b1c3","f1d3","b1c3","b1c3","b1c3","b1c3","f1d3","b1c3"],"16053660092422743798":["g7g6","e7e5","g7g6","g7g6","e7e5","c7c6"],"3998736422981301437":["f2f4","g1f3","c1g5","f2f3"],"14584943239902939444":["f8g7"],"8850731245875856030":["g1f3"],"11651078157297509283":["e8g8"],"14510127655146404740":["d2d4","d2d4","c2c3","d2d4","d2d4","d2d4","c2c3","c2c3","c2c3","c2c3","c2c3","b1c3","b1c3","c2c3","g2g3","b1c3","d2d4","d2d4"],"894944823920531142":["c5d4","c5d4","c5d4","c5d4","c5d4","c5d4","c5d4"],"6013925796646747904":["f3d4","f3d4","f3d4","f3d4","f3d4","f3d4","f3d4"],"3810499361844915502":["a7a6","a7a6","a7a6","b8c6","b8c6","b8c6","a7a6"],"16579558963243030002":["f1d3","f1d3","h2h3","f1d3"],"12906134191187318657":["g8f6","d8c7","d8c7"],"12743928185465951245":["f8g7","f8g7","f8g7","f8g7"],"29197105579859879":["c2c3","c2c3","f1e1","f1e1"],"8556067452876656673":["g8f6","e7e5"],"8630710310852080463":["c6d5","c6d5"],"339702937079627509":["c2c4","c2c4"],"9841844171730982386":["g8f6","b8c6"],"15238903320001926244":["b1c3"],"2948852210967959229":["b8c6"],"7748663110719689693":["d2d4","d2d4","d2d4","d2d4","d2d4","d2d4","d2d4"],"12591474814412652191":["f8g7","f8g7","f8g7","g8f6","f8g7","f8g7","g8f6","f8g7","g8f6","f8g7","f8g7"],"2162249090246653237":["b1c3","b1c3","c2c4","g1f3","g1f3","b1c3","b1c3","c2c4"],"16543624366772107244":["d7d5","a7a6","d7d6","c7c6"],"6988707704707938762":["e4d5"],"5126199104108084172":["g8f6"],"2075559203412638298":["f1c4"],"9186897050412788988":["b8d7"],"14803820916378539234":["e5d4","e5d4","e5d4","e5d4","e5d4"],"6201375540919042295":["c2c3","c2c3","c2c3","d1d4","d1d4"],"2365883673103208305":["d4c3","d7d5","d7d5"],"16216020063049160161":["b1c3"],"7986081747489684019":["b8c6"],"14211561535377760048":["f1c4"],"12000456674659273110":["g8f6"],"9443689642921087454":["e7e6","d7d5","g8f6","d7d5","g8f6","g8f6","d7d5","d7d5","d7d5","g7g6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","d7d5","g8f6","d7d5","g7g6","d7d6","c7c6","d7d5","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","c7c6","g8f6","d7d5","g8f6","d7d5","d7d5","g8f6","g8f6","e7e6","d7d5","g8f6","d7d5","d7d5","d7d5","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","d7d5","g8f6","g8f6","g7g6","d7d5","g8f6","e7e6","g8f6","g8f6","d7d5","g8f6","g8f6","d7d5","g8f6","d7d5","g8f6","g8f6","g8f6","d7d5","g7g6","d7d5","d7d5","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","d7d5","g8f6","d7d5","g8f6","d7d5","g8f6","g8f6","g8f6","g8f6","d7d5","d7d5","g8f6","g8f6","g7g6","g8f6","g8f6","g8f6","g8f6","e7e6","d7d6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","d7d5","g8f6","g8f6","g8f6","d7d5","g8f6","c7c5","g8f6","g8f6","g8f6","d7d5","d7d5","g8f6","d7d6","d7d5","g8f6","d7d5","g8f6","g8f6","d7d5","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","d7d5","g8f6","d7d5","g8f6","g8f6","d7d5","d7d5","d7d5","g8f6","d7d5","g8f6","g8f6","g8f6","g8f6","g8f6","d7d5","g8f6","g8f6","d7d5","d7d5","d7d5","g8f6","d7d5","d7d5","g8f6","g8f6","d7d5","g7g6","g8f6","d7d6","d7d5","g8f6","g8f6","d7d5","g8f6","g8f6","g7g6","g8f6","d7d5","g8f6","d7d5","d7d5","g8f6","g8f6","g8f6","e7e6","g8f6","g8f6","e7e6","d7d5","g8f6","g8f6","f7f5","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","d7d6","g8f6","g8f6","d7d5","g8f6","g8f6","g8f6","g8f6","g8f6","d7d5","g8f6","f7f5","g8f6","g8f6","g8f6","d7d5","g8f6","d7d5","g8f6","d7d5","d7d5","e7e6","g8f6","d7d5","d7d5","g8f6","g8f6","d7d5","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","d7d5","f7f5","d7d5","d7d5","g8f6","g8f6","d7d6","g8f6","g8f6","g8f6","g8f6","d7d5","d7d5","g8f6","g8f6","d7d5","d7d5","g8f6","d7d6","g7g6","g8f6","g8f6","g8f6","d7d5","g8f6","g8f6","g8f6","g8f6","e7e6","d7d5","g8f6","d7d5","d7d6","g8f6","d7d5","d7d5","g8f6","d7d5","d7d5","g7g6","g8f6","d7d5","c7c6","g8f6","g8f6","d7d5","d7d6","g8f6","g8f6","g8f6","e7e6","g8f6","d7d5","g8f6","g8f6","d7d5","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","f7f5","g8f6","g8f6","g8f6","d7d5","g8f6","g8f6","g8f6","d7d5","g8f6","g8f6","g8f6","d7d5","g7g6","d7d5","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","d7d5","g8f6","g8f6","g8f6","g8f6","g8f6"],"17688252038015238540":["e2e4","c2c4","c2c4","c2c4","c1f4","g1f3","c2c4","g1f3","c2c4"],"1094931570473793339":["b1c3"],"17629027193924482530":["e7g6"],"3610876807805302898":["g1f3"],"16823343352698640719":["f8e7"],"18281507589162513886":["g2g3"],"15083202811966811622":["e8g8"],"621185887674936484":["f8b4","b7b5","f8c5"],"2733759415179717312":["d2e4","d2e4","d2e4"],"3299974724469330366":["g8f6","b8d7","b8d7","b8d7"],"8513467348835455016":["e4g3"],"797798754182638554":["c7c5"],"9730558558930829353":["c3e4"],"2173170278717979762":["g1f3","g1f3","g1f3"],"14216819679419014479":["f8e7","g8f6","g8f6"],"460664201775194104":["c2c4","c2c4","g1f3","g1f3","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","g1f3","b1c3","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","g1f3","c2c4","c2c4","c2c4","c1g5","c2c4","b1c3","c1g5","c2c4","c2c4","c2c4","g1f3","c2c4","c2c4","g1f3","c2c4","c2c4","c1f4","c1f4","c1f4","c1f4","c1f4","c1f4","c2c4","c2c4","c2c4","c2c4","e2e3","c2c4","g1f3","c2c4","c2c4","c2c4","c2c4","c2c3","c2c4","g1f3","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4"],"9963937660605248767":["e7e5","e7e6","c7c6","c7c6","d5c4","e7e6","e7e6","c7c6","d5c4","c7c6","e7e6","e7e6","e7e6","c7c6","e7e6","c7c6","e7e6","e7e6","e7e6","c7c6","c7c6","e7e6","e7e6","e7e6","c7c6","c7c6","e7e6","d5c4","e7e6","c7c6","c7c6","e7e6","c7c6","c7c6","c7c6","d5c4","d5c4","e7e6","e7e6","e7e6","e7e6","c7c6","e7e6","c7c6","d5c4","c7c6","c7c6","e7e6","e7e6","e7e6","g8f6","d5c4","d5c4","c7c6","c7c6","c7c6","c7c6","c7c6","c7c6","e7e6","e7e6","e7e6"],"17746977930792137":["e2e3"],"12471116605901325421":["e5d4"],"3921585210348687480":["e3d4"],"2376819177394248554":["f8b4"],"16842690150593745736":["b1c3"],"1308957419023290769":["d8e7"],"15627646045920596040":["c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","g1f3","g1f3","g1f3","c2c4","c2c4","g1f3","g1f3","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","g1f3","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","g1f3","g1f3","c2c4","g1f3","c2c4","c2c4","c2c4","g1f3","g1f3","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","g1f3","g1f3","g1f3","g1f3","c2c4","c2c4","g1f3","c1g5","c1g5","b1c3","c2c4","c1g5","c1g5","c2c4","b1c3","b1c3","c1g5","c1g5","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","g1f3","c2c4","c2c4","c2c4","c2c4","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","g1f3","c1f4","c1f4","c2c4","c2c4","b1c3","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","g1f3","g1f3","c2c4","g1f3","c2c4","c2c4","c2c4","g1f3","c2c4","c2c4","c2c4","c2c4","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c1g5","c1g5","g1f3","g1f3","g1f3","g1f3","b1c3","c2c4","c2c4","g1f3","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4"],"6107745739976031055":["e7e5","e7e6","e7e6","g7g6","g7g6","e7e6","g7g6","e7e6","g7g6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","g7g6","c7c5","g7g6","e7e6","e7e6","c7c5","g7g6","e7e6","e7e6","e7e6","g7g6","c7c5","e7e6","g7g6","g7g6","e7e6","b8c6","e7e6","e7e6","g7g6","g7g6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","g7g6","e7e6","g7g6","e7e6","e7e6","e7e6","e7e6","g7g6","e7e6","e7e6","b7b6","e7e6","g7g6","g7g6","e7e6","g7g6","e7e6","e7e6","g7g6","g7g6","g7g6","g7g6","e7e6","e7e6","g7g6","g7g6","e7e6","e7e6","g7g6","g7g6","e7e6","g7g6","e7e6","e7e6","g7g6","g7g6","e7e6","e7e6","e7e6","e7e6","e7e6","g7g6","e7e6","e7e6","g7g6","e7e6","e7e6","e7e6","b8c6","e7e6","g7g6","c7c5","e7e5","g7g6","e7e6","g7g6","e7e6","c7c5","g7g6","e7e6","e7e6","e7e6","g7g6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","g7g6","g7g6","g7g6","e7e6","g7g6","g7g6","e7e6","g7g6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","g7g6","e7e6","g7g6","e7e6","e7e6","e7e6","g7g6","e7e6","e7e6","e7e6","e7e6","g7g6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","c7c5","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","g7g6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6","g7g6","e7e6","e7e6","e7e6","e7e6","e7e6","e7e6"],"16049450462512464761":["d4e5","d4e5"],"4766749505263334409":["f6g4","f6g4"],"16070459810519704933":["e2e4","e2e3"],"1946315100406634607":["g4e5"],"659047132606507997":["f2f4"],"17596437774491260500":["e5c6"],"11339917551484771745":["b8c6","d7d5","d7d5","g8f6","g8f6","d7d5","g8f6","d7d5","d7d5","c7c5","g8f6","d7d5","d7d5","c7c5","d7d5","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g7g6","d7d5","d7d5","d7d5","g8f6","g8f6","g8f6","d7d5","g8f6","g8f6","g8f6","g8f6","d7d5","d7d5","g8f6","c7c5","d7d5","g8f6","d7d5","d7d5","g8f6","d7d5","g8f6","g8f6","d7d6","g8f6","g8f6","d7d6","d7d5","d7d5","c7c6","c7c5","d7d5","d7d5","d7d5","g8f6","d7d5","g8f6","g8f6","d7d5","d7d5","b7b5","d7d5","g8f6","g8f6","c7c5","g8f6","d7d5","d7d5","d7d5","g8f6","d7d5","d7d5","d7d5","d7d5","g8f6","d7d5","g8f6","g8f6","g8f6","g8f6","g8f6","g7g6","g8f6","g7g6","c7c5"],"3941390798203271330":["d2d4"],"17548150219293529568":["d7d5"],"8569698848803085254":["c2c4"],"18072435778204790977":["e7e6"],"10140350061634543763":["b1c3"],"8588041256518869578":["g8f6"],"3224840051360703452":["c1g5"],"16905028413059004436":["f8e7"],"1744398265243390855":["c2c4","c2c4","e2e3","c2c4","c2c4","g2g3","d2d4","g2g3","g2g3","g2g3","g2g3","g2g3","e2e3","e2e3","e2e3","g2g3","g2g3","g2g3","g2g3","g2g3","d2d4","g2g3","g2g3","g2g3","g2g3","d2d4","g2g3","e2e3","e2e3","g2g3","e2e3","g2g3","g2g3","g2g3","g2g3"],"10670936126110070912":["e7e6","e7e6","d5d4","d5d4"],"16312378629039253714":["g2g3","g2g3"],"14882020373143564522":["g8f6","g8f6"],"12890274968567184062":["b2b3","b2b3"],"4487015268622733779":["f8d6","f8d6"],"11943384304268263767":["e4d5","e4d5"],"9394622017322189649":["d8d5","d8d5"],"453939809189101211":["c3d4","c3d4"],"12320268125143036605":["b8c6","b8c6"],"7779729978194095878":["g8f6","f8c5"],"3465823137605430928":["d2d3"],"11438527407269451076":["b8c6"],"3841580312224870471":["f2f4"],"14431351788258790862":["d7d6"],"17437062118399886499":["g1f3"],"3069220094484882846":["f8e7"],"16577243330810021022":["c2c3"],"10436188760530291480":["g8e7"],"12581780687693840549":["e1g1"],"15054985910253738933":["c5b6"],"14392440628058055360":["d7d5","f8e7","g7g5","g7g5","g7g5","g7g5","g7g5"],"4816065901379497190":["e4d5"],"7280368029776998112":["g7g5"],"16522265555835752810":["f1c4"],"9725898139054950348":["f8g7"],"16992483941090005236":["d7d5","d7d5","d7d5"],"7979054769927315154":["g1f3","g1f3","d2d3"],"13105370460902735855":["c8g4","c8g4"],"13190034841581924613":["h2h3","h2h3"],"6917449258172414199":["g4f3","g4f3"],"17188697868358537863":["d1f3","d1f3"],"13266979631317484873":["e7e6","d5d4"],"18172295748331349165":["g1f3","b1c3","b1c3","b1c3","b1c3","b1c3","g1f3","g1f3","g1f3","b1c3","b1c3","b1c3","g1f3","b1c3","g1f3","g1f3","g1f3","g1f3","b1c3","b1c3","g1f3","b1c3","g1f3","b1c3","b1c3","b1c3","b1c3","g1f3"],"2833918952001226128":["g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","d5c4","g8f6","g8f6","d5c4","g8f6","g8f6"],"8987547339916674054":["g2g3","g2g3","g2g3","g2g3","g2g3","g2g3","g2g3","b1c3","b1c3","b1c3","h2h3","b1c3","g2g3","b1c3","e2e3","b1c3","b1c3","b1c3","g2g3","b1c3","b1c3","b1c3","g2g3","g2g3","g2g3","g2g3","g2g3","g2g3","g2g3","b1c3","b1c3","g2g3","b1c3","b1c3","b1c3","c1g5","g2g3","g2g3","b1c3","b1c3","b1c3","b1c3","e2e3","b1c3","b1c3","b1c3","g2g3","g2g3","g2g3","b1c3","b1c3","e2e3","b1c3"],"5790668087060225086":["f8e7","d5c4","f8b4","f8b4","f8b4","d5c4","f8b4","d5c4","d5c4","d5c4","f8e7","f8b4","f8e7","f8e7","f8b4","f8b4","d5c4","f8b4","d5c4","d5c4","d5c4","f8e7"],"4940701808550080687":["f1g2","f1g2","f1g2","f1g2","f1g2"],"17393118791544350934":["e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8"],"1981041941088873272":["f8c5","g8f6","g8f6","f8c5","g8f6","f8c5","g8f6","f8c5","f8c5","g8f6","f8c5","g8f6","f8c5","g8f6","f8c5","g8f6","f8c5","f8c5","f8c5","f8c5","g8f6","g8f6","f8c5","f8c5"],"13142444336106062267":["b2b4","c2c3","b1c3","c2c3","e1g1","d2d3","e1g1","e1g1","b2b4","b2b4","b2b4","c2c3","b2b4","b2b4"],"2059872162009066911":["c5b6","c5b4","c5b4","c5b4","c5b4","c5b4"],"18189206131620234378":["a2a4"],"10926986651431801156":["a7a6"],"2500870339942132509":["g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","g1f3","g1f3","g1f3","g1f3","b1c3","b1c3","b1c3","g1f3","g1f3","g1f3","g1f3","b1c3","b1c3","b1c3","b1c3","b1c3","g1f3","g2g3","b1c3","g1f3","g1f3","g1f3","b1c3","g1f3","g1f3","b1c3","b1c3","b1c3","b1c3","g1f3","b1c3","b1c3","b1c3","g2g3","g1f3","b1c3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","g1f3","b1c3","b1c3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","b1c3","g1f3","g1f3","g1f3","g1f3","g2g3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","g2g4","g1f3","b1c3","b1c3","b1c3","b1c3","g1f3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","g2g3","g1f3"],"18000901611687889440":["f8b4","f8b4","d7d5","d7d5","f8b4","f8b4","d7d5","d7d5","d7d5","f8b4","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","f8b4","b7b6","b7b6","d7d5","d7d5","d7d5","c7c5","d7d5","d7d5","d7d5","f8b4","d7d5","d7d5","d7d5","b7b6","d7d5","d7d5","f8b4","d7d5","b7b6","b7b6","c7c5","d7d5","b7b6","d7d5","d7d5","d7d5","d7d5","d7d5","b7b6","d7d5","f8b4","d7d5","c7c5","d7d5"],"3499690135436263938":["b1d2","b1d2","b1d2","b1d2","c1d2","c1d2","b1d2","b1d2","c1d2"],"7638067310961601074":["b7b6","b7b6","e8g8","b7b6","d7d5","e8g8"],"186500058724703657":["a2a3","a2a3","a2a3"],"14486573631660659689":["b4d2","b4d2","b4d2"],"15924859471947038405":["g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","e7e6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6"],"9722783023074688851":["c2c4","c2c4","c2c4","c1f4","c1g5","c2c4","c2c4","c1f4","c1f4","g2g3","c1g5","c1g5","c2c3","c2c3","e2e3","c2c4","c1f4","c1f4","e2e3","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c4","c2c3","c2c4","c1f4","c2c4","c2c4"],"778520757710820436":["c7c6","c7c6","e7e6","e7e6","d5c4","d5c4","c7c6","c7c6","e7e6","c7c6","c7c6","c7c6","e7e6","d5c4","e7e6","e7e6"],"11004318564803921903":["e2e3","e2e3","e2e3","b1c3","b1c3","b1c3","b1c3","e2e3","c4d5","c4d5","c4d5","c4d5","b1c3","d1b3","d1b3","b1c3","b1c3","b1c3","b1c3","e2e3","b1d2"],"3862527020462264139":["c8g4","c8g4","c8f5","c8f5","c8f5"],"3983919777398997409":["b1c3","b1c3"],"14762413979769226104":["e7e6","e7e6"],"9393009929428972253":["f1g2","f1g2","f1g2","f1g2","f1g2","f1g2","d1a4","f1g2","f1g2"],"4011176468539861668":["c7c5","c8d7","c7c5","a7a6","b8d7","a7a6","c7c6","c7c5","f8b4"],"7690717602231305109":["e2e4","e2e4","e2e4","g1f3","c2c4","c2c4","c2c4","c2c4","c2c4","e2e4"],"3990527121806217008":["c1e3"],"4929621534230205630":["b7b5"],"2625003469190588803":["d1d2"],"897814124866319907":["d7d6"],"13652452506622667012":["g2g3","g2g3","g2g3","g2g3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","g1f3","g1f3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","g2g3","b1c3","b1c3","b1c3","b1c3","g2g3","g2g3","f2f3","f2f3","f2f3","f2f3","b1c3","b1c3","b1c3","b1c3","g2g3","g2g3","b1c3","g1f3","b1c3","b1c3","b1c3","b1c3","h2h4","b1c3"],"10489440406649270588":["c7c5","c7c5","f8g7","f8g7","c7c6","f8g7","f8g7","f8g7","f8g7"],"8637952290091858497":["d4c5","d4c5"],"577315104759885101":["b8a6","b8a6"],"5081083577925480849":["f1g2","f1g2"],"17542507818341132776":["a6c5","a6c5"],"11033252802844267548":["c1d2","c1d2","c1d2","c1d2","c1d2","c1d2","c1d2","c1d2"],"12722851750742916888":["b4e7","b4e7","b4d6","b4e7","a7a5","a7a5","b4d6","b4e7"],"5867448642441809156":["e1g1","e1g1","b5c6","e1g1","c2c3","e1g1"],"3393504377596827156":["g8e7","g8e7","g8e7","g8e7"],"1249637836289596841":["d2d4","d2d4","d2d4","f1e1"],"15303993872517230827":["c5d4","c5d4","c5d4"],"2390786671385950870":["f1g2","f1g2","f1g2","f1g2","f1g2","f1g2"],"10726981343916286703":["e8g8","e8g8","e8g8","e8g8","e8g8","e8g8"],"11418042347632888580":["b1c3","b1c3","b1c3","b1c3","b1c3","b1c3"],"7341449755079477725":["d7d6","d7d6","d7d6","d7d6","d7d6","d7d6","d7d6"],"13800344968392520706":["d7d5","d7d5","g8f6","d7d5","g8f6","d7d5","d7d5"],"4246488407740971556":["e4d5","e4d5","e4d5","e4e5","e4e5"],"2103608705476573218":["d8d5","d8d5","e6d5"],"11033069023595841000":["d2d4","d2d4"],"6640692307110848682":["g8f6","g8f6"],"7158243423025775577":["a2a3","a2a3"],"12162816669612690841":["b4e7","b4e7"],"4652262204718639790":["d2d3","d2d3","d2d3","d2d3","d2d3","d2d3","d2d3","d2d3","d2d4","d2d4"],"17164594797838225786":["f8e7","h7h6","f8e7","f8c5","f8c5","f8c5","h7h6","d7d5"],"18084371331159293419":["e1g1","e1g1"],"9551550820281100027":["e8g8","d7d6"],"8990222109112146031":["e1g1","e1g1"],"195577092112237439":["d7d6","d7d6"],"16781826789289200440":["b8c6"],"13887324324685303357":["g8f6","g8f6","g8f6","d8e7"],"11193585894164008875":["d2d3","d2d4","d2d4"],"3887548509920474239":["d7d6","d7d6","d7d6"],"5617202455357997922":["g8f6"],"1592720841146794740":["d2d3"],"13312280957642488096":["d7d6","a7a6"],"10530054964881890866":["d7d6","g8f6","e7e6","d7d6","d7d6"],"12364865130337916767":["b1c3","b1c3","b1c3"],"5769053880403187078":["g8f6","b8d7","e7e5","g8f6"],"856245803574826000":["f1e2","h2h3","h2h3","h2h3","f2f3","h2h3","g1f3","f1e2","g1f3","f1e2","g1f3","g1f3","f2f3"],"8674351781538062003":["e8g8","e8g8","e8g8"],"10649727320182616230":["e6d5","e6d5","e6d5","e6d5"],"12456876677158678637":["f1d3","g1f3","g1f3","f1d3"],"18001475900220437022":["f8d6","c7c6"],"6740335540366615354":["b1c3"],"11969833149163938275":["g8e7"],"14317310571807890487":["c2c4","c2c4","b2b3","c2c4","c2c4","g2g3","g2g3","g2g3","g2g3","g2g3","g2g3","g2g3","g2g3","g2g3","g2g3","c2c4","c2c4","c2c4","b2b3","c2c4","g2g3","b2b3","g2g3","c2c4","c2c4","c2c4","c2c4","c2c4","g2g3","g2g3","b2b3","b2b3","g2g3","g2g3","c2c4","g2g3","c2c4","d2d4","g2g3"],"5373587376344037168":["g7g6","g7g6","b7b6","e7e6","e7e6","e7e6","e7e6","c7c5","c7c5","g7g6","g7g6","g7g6","e7e6","b7b6","e7e6","g7g6","g7g6","e7e6"],"11756227247249155451":["b1c3","b1c3","b1c3","b1c3","g2g3","b1c3","g2g3"],"6391059522113202082":["f8g7","f8g7","f8g7","f8g7","d7d5"],"16721205094508964872":["e2e4","e2e4","e2e4","e2e4"],"3173536045889753346":["d7d6","d7d6","d7d6","d7d6"],"1562815427595229295":["d2d4","d2d4","d2d4","d2d4"],"15025437670393869613":["e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8"],"1809390519403374854":["c7c5","b4d2","c7c5"],"18405629733685977723":["d2b4","e2e3"],"17408358932759907386":["c5b4"],"5107180496752749533":["f8g7","d7d5","f8g7","d7d5","f8g7","f8g7","f8g7","f8g7","f8g7","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","f8g7","d7d5","d7d5","f8g7","d7d5","d7d5","f8g7","d7d5","d7d5","f8g7","d7d5","f8g7","f8g7","d7d5"],"17752173974206799991":["e2e4","e2e4","e2e4","e2e4","e2e4","g1f3","e2e4","e2e4","e2e4","g2g3","e2e4","e2e4","e2e4"],"3628609804068937085":["e8g8","d7d6","d7d6","d7d6","d7d6","d7d6","d7d6","e8g8","d7d6","d7d6","d7d6","d7d6"],"4103769729743631510":["f1e2","f1e2"],"5436697054627495477":["d7d6","d7d6"],"2206826647629157435":["e1g1","e1g1"],"6977555837124251435":["c8d7","c8d7"],"9373323717903671066":["f1e1","f1e1"],"477578140849075214":["a7a6","g8f6"],"12876569570225827890":["d4d5","d4d5","d4d5","d4d5","d4d5","d4d5"],"628956516477347439":["b7b5","e7e6","b7b5","b7b5","b7b5","b7b5"],"7547727908582792018":["c4b5","c4b5","c4b5","c4b5","d1c2"],"11058303618664092165":["a7a6","a7a6","a7a6","a7a6"],"5440644662471895769":["b5a6","b5b6","e2e3","e2e3"],"4154168095160724798":["c8a6"],"1746808887790790468":["b1c3","b1c3","c4d5","c4d5","c4d5","g1f3","b1c3","g1f3","g1f3","e2e3","g1f3","b1c3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","b1c3","b1c3","c4d5","c4d5","c4d5","c4d5","c4d5","g1f3","g1f3"],"16405190465850271133":["g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6"],"13277954457521038347":["c4d5","c4d5","c4d5","e2e3","g1f3","g1f3","c4d5","c4d5"],"13145548778944933910":["c6d5","c6d5","c6d5","c6d5","c6d5"],"14204056076390710700":["f2f3","f2f3","f2f3","c1g5","c1g5","c1f4"],"12371132606005498589":["b8c6","b8c6","b8c6"],"4579366209504666188":["g1f3"],"16494928108337857393":["c7c5"],"186917073397647372":["e4d5"],"2686144682374580746":["e6d5"],"14090210237421479419":["c1f4","c4d5","c4d5","c4d5","c4d5","c4d5","c4d5","c4d5","c4d5","c4d5","g1f3","g1f3","g1f3","c4d5","c4d5","c4d5","g1f3","c4d5","g1f3"],"1792042390923808664":["f8g7"],"12133437065441526834":["e2e3"],"382773032544685206":["e8g8"],"13156395808987896582":["e4e5","e4e5"],"15559372342507830626":["f6d7","f6d7"],"3362778333975377047":["g1f3","f1d3"],"17712114062392628650":["c7c5"],"17677705321074507529":["f1d3","b1c3","b1c3"],"11555800094820105594":["d7d6"],"11089239523196220439":["g1f3"],"4791839219342595370":["a7a6"],"10410063470737468918":["c2c3"],"16621942944552952432":["f8g7"],"13453430573722802355":["c2c4","g1f3","e2e4","c2c4","g1f3","g1f3","g1f3","b1c3","e2e4"],"3933807376954504116":["e7e5","g8f6"],"13614303320549804930":["b1c3"],"5150042232243898715":["e5d4"],"15854547129546792270":["d1d4"],"11651301901869429363":["b8c6"],"746583492712929136":["d4d2"],"13751420454973048837":["g7g6"],"1257722956240912997":["c2c4","c2c4","e2e4"],"11337754308345571682":["g8f6","d7d5","d7d5"],"14319757469619710196":["b1c3"],"4408715271549708845":["d7d5"],"6359431977275087388":["e2e4","e2e3","e2e4","g1f3","g1f3","e2e3","g1f3","g1f3"],"11260741761627940630":["e7e5","e7e5"],"1602780858620092192":["g1f3","g1f3"],"14797372916789870109":["e5d4","f8b4"],"6207331129989917192":["f1c4"],"12845794071738953401":["b8c6"],"9740501023354455775":["c7c5","c7c5","a7a6","f8b4","c7c5","f8e7","b8d7","f8e7","f8b4","c7c5","d5c4","c7c6","a7a6","c7c6","c7c5","d5c4","c7c6","f8b4","d5c4","f8e7","d5c4","f8e7","f8b4","f8b4","a7a6","d5c4","b8d7","f8b4","c7c5","c7c5","f8e7","f8e7"],"7015737115117358498":["c4d5","e2e3","c4d5","c4d5","c4d5","e2e3","c4d5"],"8031743812832729535":["c5d4","c5d4","f6d5","f6d5","f6d5"],"14731737336128177414":["b8c6","b8c6","b8c6"],"9137085317498418749":["b1c3"],"9609109360442389732":["e6d5"],"13424865216444331055":["c4d5"],"12998558426126049330":["d7d6"],"13049208820184406819":["c7c6","e7e6","c7c5","g8f6","g8f6","e7e6","g8f6"],"2838103014801967256":["c2c4"],"12340259369885311903":["e7e6","g8f6"],"15940098024346334157":["b2b3"],"5877473380283504800":["g8f6"],"756268371085269302":["c1b2"],"14556236623881307609":["f8d6"],"6308451898672685321":["b5d7","c2c4"],"4449657111094941009":["d8d7"],"8437469234707909361":["c2c4"],"17957108133876916726":["e7e5"],"10517661917435401741":["b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","b1c3","d2d4","b1c3","b1c3","b1c3","d2d4","g1f3","g2g3","b1c3","g2g3","b1c3","b1c3","b1c3","b1c3","b1c3","g2g3","b1c3","d2d4","g1f3","g1f3","b1c3","b1c3","b1c3"],"7665449183234921684":["e7e5","e7e5","e7e5","b8c6","e7e5","g7g6","e7e5","e7e5","e7e5","e7e6","c7c5","e7e6","c7c6","d7d5","e7e6","g7g6","e7e5","e7e6","e7e6","e7e6","e7e5"],"16147987561512660194":["g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g2g3","e2e3"],"4286795552735432159":["b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6","b8c6"],"10416853657022933212":["e2e3","e2e3","e2e4","e2e4","e2e4","e2e4","g2g3","g2g3","a2a3","g2g3"],"4448812646389194872":["f8b4","f8b4"],"17653040172234500186":["d1c2","d1c2"],"5036502799952303166":["b4c3","b4c3"],"4638709225839374253":["g2g3","g1f3","g1f3","g2g3","g2g3","g2g3","g2g3","g2g3","b1c3","b1c3","g2g3","g2g3","g2g3","b1c3","g2g3","g2g3","d2d3","d2d3","e2e3","b1c3","b1c3","b1c3","b1c3"],"7820142549010249621":["b8c6","f8c5","g8f6","g8f6","b8c6","g8f6","g8f6","g8f6","d7d6","g8f6","c7c6"],"14369687703950054038":["f1g2","b1c3"],"8258266533584702191":["f8c5"],"16106567661540008044":["e2e3"],"8262776288335194312":["a7a6"],"11555311621485989908":["g1e2"],"13040275514042260970":["c5a7"],"5338786800259348314":["e7e6","g7g6","g7g6","g7g6","d7d5"],"4350719674051340040":["g2g3"],"1190752675826106160":["d7d5"],"10803085658971812118":["f1g2"],"2313836828665080175":["f8e7"],"3805565573373871614":["e1g1"],"5378826884359659246":["e8g8"],"8537608491875127911":["g2g3","g2g3"],"6529400261470095967":["b8c6","g7g6"],"17397631944163143516":["f1g2"],"4936206251810420517":["e7e5"],"14913922109940764435":["d2d3"],"6943467542133191879":["f8b4"],"16362178584101314018":["a7a5","e8g8","e8g8","e8g8"],"12200784307308905817":["d2d3"],"573407933016997517":["d8c7"],"14223835338198373384":["d7d6","d7d6","g8f6"],"18221459092580212069":["f1c4","c2c3"],"11487579465577253827":["g8f6"],"14159915763606456917":["d1e2"],"5946070368525734353":["e8g8"],"528813709611831216":["e4d5","e4d5","e4d5","e4d5","e4d5","e4d5","e4d5","e4d5","e4d5"],"2344601935407631798":["g8f6","g8f6","g8f6","d8d5","d8d5","d8d5","d8d5","d8d5","d8d5"],"8892225506042186784":["d2d4","g1f3","b2b3"],"13714208902744711522":["f6d5"],"2431048915374324798":["g1f3"],"18075266295579434243":["g7g6"],"1399614215527190344":["f1c4"],"8133458726885923310":["d5b6"],"1073458382634834944":["d7d5"],"11530177163818331421":["c8g4"],"11732054292150051831":["h2h3"],"5342250502179564037":["g4f3"],"18110749063492206709":["d1f3"],"12520302024680724411":["d8d5"],"10856056287042004054":["e1g1"],"16779583835439054662":["e7g6"],"14637431381026140944":["d7d5","d7d5","d7d5"],"5640961244981869878":["e4e5","d2d3","d2d3"],"3400691197922876242":["c8f5"],"12405384177415394015":["f3h4"],"11356673517428428920":["f5g6"],"940916283301189306":["d2d4"],"14428374277021858808":["e7e6"],"10474633245981484678":["e1g1","e1g1","c2c3","b1c3","c2c3"],"17233063852095765910":["c8d7","c8d7"],"6241414395725855133":["d2d4"],"10639994882660668639":["c5d4"],"14751084060885581081":["f3d4"],"12331486424488701751":["f8g7"],"1990028255389066397":["c2c4"],"10934070309123985306":["b8c6","b8c6","b8c6"],"10677929950469043692":["e4c3","e4c3","e4c3"],"1471912402293780975":["e4e5","c1g5","e4e5","e4e5","e4e5","e4e5"],"8436471858628941707":["f6d7","f6d7","f6d7","f6d7","f6d7"],"10111528752130580094":["f2f4","c3e2","f2f4","f2f4","f2f4"],"8161966040611644407":["c7c5","c7c5","c7c5","c7c5"],"9710347368368175195":["e4e5","g1e2","e4e5","e4e5","e4e5","e4e5"],"16697943921311211071":["c7c5","c7c5","c7c5","c7c5","c7c5"],"129146609016689986":["a2a3","a2a3","a2a3","a2a3","a2a3"],"14580090494323153666":["b4c3","b4c3","b4c3","b4c3","b4a5"],"4515031458725109304":["d5e4"],"17735740728237076072":["f3e4"],"12062251916157219222":["e7e5"],"3250950765068847520":["g1f3"],"17760330550009194653":["c8e6"],"4839575596358482937":["c2c3","c2c3","b1c3","c1g5"],"7411126053769636831":["e7e6","c5d4","c5d4","c5d4","c5d4"],"1202014494049860493":["c2c3"],"7382685429818356747":["b8c6"],"6801197100818309865":["e5d4","e5d4"],"5619195683159520329":["f6e4","f6e4","f6e4","f6e4"],"17315261855013671770":["f1d3","f1d3","f1d3","f1d3"],"11907952033337364777":["d7d5","d7d5","b8c6","b8c6"],"2318132646244425487":["f3e5","f3e5"],"11414476230594673209":["b8d7","f8d6"],"570086959443214002":["g7g6"],"17175893671258516729":["b5c6"],"13795144324673486018":["d7c6"],"9917508003677480637":["d2d3"],"2812167214895635817":["f8g7"],"6186476807873625603":["e2e3","c4d5","c4c5"],"17939954232251676327":["b7b6"],"14600096376707389425":["d7d5","d7d5","d7d5","g8f6","d7d5","d7d5","d7d5","d7d5","c7c5","e7e5","e7e5","d7d5","d7d5"],"5762307724021389783":["c1b2","c1b2","c1b2","c1b2","c1b2","c1b2","c1b2","c1b2","c1b2"],"10343429540978902328":["c8g4","c8f5","c8f5","c8f5","c7c5","c8f5","c8f5","g8f6","g8f6"],"10185159318548073426":["f2f3"],"16422677713168082083":["g4f5"],"11165997187255248589":["e2e4"],"6841700112190446535":["f5g6"],"14872951109159787781":["e4d5"],"16841312468920164099":["d8d5"],"16939960875862619192":["e7e5","d7d5","d7d5","d7d5","g8f6","g7g6","g7g6"],"7020791726451952654":["c2c4"],"17099397873082312457":["b8c6","g8f6"],"5089238170528481802":["b1c3"],"13634511000674173139":["d7d6"],"9551314461825911230":["g2g3"],"12135985640344424838":["g7g6"],"4746791805951345613":["f1g2"],"17595526919986273204":["f8g7"],"7961509540109367838":["b2b3","d1e2","c2c4"],"16346543731217224051":["g8f6"],"13345469853481111781":["g1f3"],"7084248979466842584":["b8d7","c8g4","g7g6"],"5887108186475043860":["c1b2"],"10506862409610625275":["e7e6"],"16697145648480096425":["h1g1"],"16413395507577096803":["f8e7"],"253434895135994229":["d7d5","d7d5","e7e6","e7e6","c7c5","g7g6","c7c5","g7g6","e7e6","d7d5","c7c5","d7d5","d7d5","d7d5","g7g6","e7e6","e7e6","e7e6","g7g6","d7d5","d7d5","g7g6","d7d5","d7d5","e7e6","d7d5","d7d5","e7e6","d7d5","d7d5","d7d5","e7e6","e7e6","d7d5","e7e6","e7e6","d7d5","e7e6"],"6753892168587302192":["e7e6","c7c5","e7e6","c7c5","c7c5","c7c5"],"3156274665317647714":["e2e3","e2e3"],"9718791807089593798":["f8d6","f8d6","f8d6","c7c5"],"2501881693927349474":["b1d2","f3e5","b1d2"],"8923531126051058898":["e8g8","e8g8"],"4662374139822761115":["f6e4","f6e4","f6e4"],"8844040578360666465":["g5h4","g5h4","g5h4"],"2320251901593897978":["c8f5","c7c5","c8f5"],"11757307913315074679":["b1d2","b1d2"],"18033786350535535175":["c7c6","c7c5"],"8499299103407497511":["c1g5","c1g5","c1g5","c2c4","c2c3","c1g5","c1f4","c2c4","c2c4","c1f4","g2g3","e2e3","g2g3","g2g3","c2c3"],"12946094891610192623":["d7d5","h7h6","f8e7","d7d5"],"3946251286096704713":["b1d2","e2e3"],"8047192289732549881":["f8e7"],"8890436822085070952":["e2e3","e2e3"],"15441839944631417036":["b7b6","e8g8","e8g8"],"17904756983250968986":["g8f6"],"11787131239404937228":["d2d3"],"950416459634613208":["c7c5"],"16951935007448076453":["b1d2"],"12839735532700186773":["b8c6"],"1856115894827064726":["g2g3"],"3829331627591878062":["g7g6"],"9465088315907768135":["g8f6","g8f6","g8f6","g8f6"],"15615259024392175313":["f1b5","d2d4","h2h3","f1b5","f1b5"],"16963470959540383058":["f8d6","c6d4","f8b4","c6d4"],"5706684652443794550":["a2a3"],"9560586010549121590":["a7a6"],"12361927582217565072":["d5e4","d5e4","d5e4","d5e4"],"7135105516951815104":["c3e4","c3e4","g1e2","c3e4"],"14539522135797355095":["g8f6","g8f6","g8f6"],"10531938950989943745":["e4f6","e4f6","e4f6"],"15477599793873760624":["g7f6","e7f6","e7f6"],"15646623240953866692":["f8b4","f8b4","f8b4","f8b4","f8b4","f8b4","f8b4","f8b4","f8b4","f8b4","d7d5","f8b4","f8b4","f8b4","d7d5","d7d5","f8b4","f8b4","f8b4","f8b4","d7d5","f8b4","d7d5","f8b4","f8b4","f8b4","f8b4","f8b4","d7d5","f8b4","d7d5","f8b4","d7d5","f8b4","f8b4","f8b4","f8b4","f8b4","c7c6","f8b4","f8b4","f8b4","f8b4","f8b4","f8b4","d7d5","f8b4","f8b4","f8b4","d7d5","d7d5","f8b4","f8b4","f8b4","f8b4","f8b4","f8b4","d7d5","f8b4","f8b4","f8b4","f8b4","f8b4","f8b4","f8b4","f8b4","d7d5"],"1180666540095889894":["d1c2","d1c2","e2e3","e2e3","e2e3","e2e3","d1c2","d1c2","d1c2","d1c2","d1c2","d1c2","c1d2","d1c2","c1g5","c1g5","g2g3","e2e3","e2e3","e2e3","e2e3","d1c2","d1c2","d1c2","g2g3","g2g3","d1c2","d1c2","d1c2","d1c2","e2e3","e2e3","e2e3","e2e3","e2e3","e2e3","e2e3","e2e3","e2e3","e2e3","e2e3","e2e3","e2e3","e2e3","e2e3","e2e3","d1c2","d1c2","d1c2","d1c2","d1c2","g1f3","g1f3"],"11636144662482050434":["e8g8","e8g8","d7d5","e8g8","e8g8","d7d5","e8g8","d7d5","b8c6","e8g8","d7d6","d7d5","b8c6","e8g8","d7d5","d7d5","e8g8","e8g8","e8g8","e8g8","d7d5"],"12381255592077243497":["a2a3","a2a3","a2a3","a2a3","a2a3","g1f3","a2a3","a2a3","a2a3","a2a3","a2a3"],"6939835348152859177":["b4c3","b4c3","b4c3","b4c3","b4c3","b4c3","b4c3","b4c3","b4c3","b4c3"],"5186145314081228048":["d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5"],"14020481599188634422":["d2d4","d2d4","c4d5","c4d5","d2d4","d2d4","d2d4","d2d4"],"551671236195985012":["g8f6","f8b4","a7a6","c7c6","f8e7","g8f6","g8f6","f8e7","c7c6","c7c5","f8b4","g8f6","g8f6","g8f6","c7c5","c7c5","c7c6","c7c6","f8e7","g8f6","f8e7","c7c5"],"6650087000524810210":["c4d5","c4d5","c4d5","c4d5","c4d5","c4d5","c1g5","c4d5","c4d5","c4d5","c4d5","c4d5","c4d5","g1f3","g1f3","g1f3","g1f3","c4d5","g1f3","c1g5"],"5936660378379842559":["e6d5","e6d5","e6d5","e6d5","e6d5","e6d5","e6d5","e6d5","f6d5","e6d5","e6d5","e6d5","e6d5"],"7887941416437068596":["c1g5","c1g5","c1g5","c1g5","c1g5","c1g5","c1f4","c1f4","c1g5","c1g5","c1g5","c1g5"],"12334743496750033148":["c7c6","f8e7","c7c6","f8e7","c7c6","c7c6","f8e7","f8b4","f8e7","f8e7"],"2404517378826761210":["g5h4"],"8922166405831774561":["d7d6"],"4786043767159166988":["b1d2"],"1947183485638881340":["g7g5"],"8311766651675220946":["b8c6","f8b4"],"2126161864446612371":["e5d4"],"9727685523403278214":["f3d4"],"16243343854501631400":["f8b4"],"16570344052193753608":["d4d5","c2c3","c2c3"],"6916952113207929941":["b7b5"],"4609884302187483496":["c2c3"],"5270163474536259310":["c8b7"],"17992770315249938805":["e2e4"],"4446218372357213311":["e7e6"],"16875043787478098750":["c1g5","g2g3","e2e3","c1g5","b1d2"],"3200482480922671350":["f8g7","f8g7"],"11301317531283125084":["b1d2","b1d2"],"14247383747912260460":["e8g8","d7d5"],"14920440682649891463":["c2c3"],"13387782392007245057":["d7d6"],"14817089354276172262":["f6d5","f6d5","f6d5","f6d5","f6d5","f6d5","f6d5","f6d5","f6d5","f6d5","f6d5","f6d5","f6d5"],"5930847147724336314":["g1f3","e2e4","e2e4","e2e4","c1d2","c1d2","c1d2","e2e4","e2e4","e2e4","e2e4","e2e4","e2e4"],"9883843142859951495":["f8g7"],"6574802468427820849":["e7e6"],"13641354086896200002":["e8g8","e8g8","e8g8","e8g8","e8g8","b4c3","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","e8g8","b7b6","e8g8","e8g8","e8g8"],"13251629644267684009":["f1d3","f1d3","f1d3","c1d2","f1d3","a2a3","f1d3","a2a3","c1d2","f1d3","f1d3","a2a3","a2a3","a2a3","a2a3","a2a3","a2a3","a2a3","a2a3","f1d3","f1d3","f1d3"],"16351056570317816538":["d7d5","d7d5","b7b6","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5","d7d5"],"1591867025046500185":["c6d5","c6d5","c6d5","c6d5","c6d5","c6d5","c6d5","c6d5"],"7307052916146700003":["g1f3","g1f3","g1f3","c1g5","b1c3","c1f4","c1f4","c1f4"],"13691841304655405022":["b8c6","b8c6","b8c6"],"1580457795655842525":["b1c3","b1c3","b1c3"],"17184031243909642244":["g8f6","g8f6","g8f6"],"14908752150547197526":["c4d5","e2e3"],"13892510677110291019":["e6d5"],"18435701811338754688":["c1f4"],"2633519049669071075":["g8f6"],"6095363148772283862":["f8c5","f8d6","h7h6","f8b4","f8c5"],"17981272590423359317":["f3e5","f3e5"],"5172846917544598115":["c6e5","c6e5"],"13946800911634870743":["e2e4"],"399677706902932701":["e7e5"],"10372907644048536811":["g1f3"],"17352222029239993586":["d2d4"],"3889516155375184304":["e5d4"],"14294529789732609219":["d2d4"],"240222063825613185":["e5d4"],"11170314112726847120":["b8c6","e5e4"],"3524406881622453139":["d2d4"],"17716064483396634321":["e5d4"],"7971952202203681476":["f3d4"],"663802758336332010":["g8f6"],"5969655321247187324":["d4c6"],"2187277987288811593":["b7c6"],"15370820587587100328":["c4d5"],"15815098380065819317":["e6d5"],"16460196953545247358":["g1f3"],"4542637913173551939":["c7c6"],"6368904418521154592":["d2d4","e2e4","b1c3","g1f3","e2e4","g1f3"],"8676455461131418307":["g8e7","c7c5"],"5055091536257479038":["d2d4"],"9444722771620584508":["d7d5"],"466200929833095706":["e4d5"],"2424991635256889372":["e6d5"],"2212275460096790743":["b1c3"],"16511476536084304398":["b8d7"],"9499628938629547679":["g2g3","e2e4"],"12624660068380368551":["f8g7"],"2274301075854529805":["f1g2"],"12285771104700910964":["e8g8","d7d6"],"11589800394874496159":["d2d3"],"1041455045268053835":["d7d6"],"18387856988164628044":["g1f3","g1f3"],"2614944828615229297":["e7e6","e7e6"],"5926126084100222755":["b1c3","b1c3"],"12225809419318521338":["f8b4","f8b4"],"6983858037281596888":["c1d2","d1c2"],"5289195563995909852":["e8g8"],"8744535441769097867":["g8f6","b7b6","f7f5","g8f6","g8f6","d7d5"],"17684649486277825208":["e7e5","g8f6"],"9157075094496324238":["f1c4"],"11202075339609337407":["b8c6"],"3501580362126084924":["g1f3"],"17004730450408909313":["e5e4"],"11373415878090808820":["d2d3"],"3707235098701293088":["d7d6"],"11415046457804057517":["b4c3","b7b6","d7d5"],"11392180624182440382":["b1c3"],"7317856029899354983":["b8c6"],"14871985118448735844":["f2f4"],"3708039908441025517":["g7g6"],"15763102263131175334":["g1f3"],"118541696533305499":["f8g7"],"12971159600028455270":["f3d4"],"14584145304413579689":["b8c6"],"7028027748548414634":["e2e3"],"14746422019621815310":["c6d4"],"7045412349024386381":["e3d4"],"17681234809924810602":["d8f6"],"15147610775670794791":["f8b4"],"1978899623154770437":["e4e5"],"8795354450913215585":["h7h6"],"6994004677912480002":["b8c6","b8c6","b8c6","g8f6"],"14619364786043186177":["f1b5","f1b5","d4d3"],"17959435570671386498":["c8d7","c8d7"],"10549399229582865829":["d5e4"],"6551719022309230069":["a2a3"],"10479964960776899509":["b4c3"],"3478844790428694307":["d7d6"],"719943198956338766":["d2d4"],"14756307153376056076":["e5d4"],"16459135274976867732":["f1d3","e4e5"],"12783249796955125735":["d7d5"],"3750684725003188673":["e4e5"],"6157561020242091941":["f6d7"],"2460475287985223913":["d2d4"],"16649933814972634539":["g8f6"],"9589164888287532016":["f6d5"],"4684677444752680517":["d2d4"],"9526941677513444103":["c5d4"],"8619915409966812734":["c8f5"],"17634265366196242355":["e2g3"],"2881455185841278921":["f5g6"],"15579562003103669853":["g8f6"],"9501343886485790667":["d2d3"],"3278425064466293791":["c7c6"],"13764587978221483940":["c4b3"],"8503275432318510912":["c8g4"],"8417924546247764394":["f2f3"],"1901539355011925723":["g4c8"],"13409532474505942000":["f3e5"],"314071401338468038":["e8g8"],"1077244255270510381":["f1e2"],"9039744834669106574":["f8e8"],"17732694985060957603":["g7g6"],"10207310966796677852":["b7b6","g7g6"],"16631492357969069383":["e2e4"],"2506784696090654797":["c8b7"],"10545648990564440022":["b1c3"],"7624228310845641999":["c7c5"],"10358106442403765874":["b2b4"],"2686985702660321878":["e7e6"],"5998202563961135620":["a1b1"],"31177663629125392":["c5b4"],"10685338835708956148":["a7a6"],"5097515114420942120":["c4d5"],"5229973500918276405":["e6d5"],"17852805110050940749":["f6d5"],"7507392712656058897":["c1b2"],"12129170028593735422":["c7c5"],"5630245339209657731":["g1f3"],"10756366914843675838":["b8c6"],"8627590257132589392":["g8f6","f8d6"],"3194581923397112006":["f1d3"],"8743966238209266357":["c7c5"],"11635161049092548521":["c5d4","c5d4","c5d4"],"18331584942815514223":["d1d4","d1d4","d1d4"],"9498593701622636882":["b8c6","b8c6","b8c6"],"2899210158513800273":["d4d3","d4d3","d4d3"],"1497810490925422067":["g8f6","g7g6","e7e6"],"5703666270752003173":["b1c3"],"13020019651150501564":["g7g6"],"13667455902242012741":["g8f6"],"16592028827690549203":["d2d4"],"2555676126085124753":["g7g6","d7d5","d7d5","d7d5","d7d5","d7d5"],"14610740135798472922":["e2e4"],"1064199673554615760":["d7d6","d7d5","d7d5"],"17013105573636412800":["f8g7"],"6676178355782868522":["c1e3","f1e2"],"3435744293223712164":["e8g8"],"18265784181595899832":["b1c3"],"498212139356095841":["f8g7"],"12909926407421499412":["f1g2"],"493538710917660781":["f8g7"],"13143011560800717767":["e1g1"],"14416057957673521367":["b8c6"],"10414926311342153713":["d7d5","g8f6","d7d5","d7d5","d7d5","d7d5"],"1577212425441071575":["f1g2","f1g2","f1g2","f1g2","f1g2"],"11534634699881351598":["g8f6","g8f6","g8f6","g8f6","g8f6"],"18157798812358854712":["g1f3","g1f3","b2b3","g1f3","g1f3","d2d4","g1f3"],"7115327345687528865":["b1c3"],"11036528453203953528":["g8f6"],"18299113765639953697":["g8f6","g8f6"],"11969925634439011511":["c1f4","c1f4","c1f4","c1f4","c1f4","c1f4","c1f4"],"9027836471363811028":["c7c6","e7e6","g7g6","a7a6","c7c5","a7a6","e7e6","e7e6"],"17236327590402483567":["e2e3"],"4764524926078807499":["c8f5"],"13909252702800372806":["g1f3"],"1901499076464641403":["h7h6"],"7700360802280547492":["c7c5","d7d5","d7d5","e7e5"],"10136754999242338265":["c2c4"],"40360680801338590":["b8c6","g8f6"],"12349638948868268509":["f1g2"],"2202990620299164068":["g8f6"],"5007066799444375602":["g1f3","b1c3"],"11391925969027520783":["e7e6"],"16748801796860561757":["e1g1"],"10816178777831215693":["f8e7"],"15621832290762619575":["e2e3","e2e3","e2e3","b1c3","e2e3","e2e3","e2e3"],"8495661371629662739":["e7e6","e7e6","e7e6","e7e6","e7e6","e7e6"],"257080320327475777":["f1c4","f1c4","f1c4","f1c4","f1c4","f1c4","f1c4"],"16713111464990779120":["c7c5","c7c5","a7a6","c7c5","c7c5","a7a6","c7c5","c7c5"],"2599071765067502500":["a2a3","a2a3","a2a3","c4d5","c4d5","c4d5","c4d5"],"17261717764797898212":["b4c3","b4c3","b4c3"],"10605775396122391950":["d8c7","d8b6"],"10750223867549629071":["e2e3"],"4043446403239195179":["g7g6"],"15107718918219276384":["f1d3"],"9557984133790589459":["f8g7"],"14327790633553279750":["f8g7"],"8532830483377778860":["f1g2"],"14094744426403768533":["e8g8","e8g8"],"14497785215284363582":["e1g1","e1g1"],"13206526507932752430":["d7d6","d7d5","d7d5"],"7300362707947354977":["e8g8","e8g8","e8g8","c7c6","e8g8"],"3258504803564749130":["e8g8"],"2850890083324160161":["g2g3"],"826091866371895449":["d7d5"],"12062248772550727294":["b1d2"],"18305741857440317006":["d7d5"],"14076288276950779513":["g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6","g8f6"],"13151384280534173382":["b1c3","b1c3","b1c3"],"5554067471260435487":["e7e6","e7e6","e7e6"],"9945452212337382115":["g8f6"],"15126303541486179189":["f1d3"],"9576567399294210310":["e8g8"],"10798791872932476367":["e2e4","e2e3","g1f3","g1f3"],"5898042839492937925":["d5e4"],"11047490136940673173":["c3e4"],"3716225383873145090":["f8b4"],"1311565328533576976":["e2e4","e2e4","b1c3"],"15435121249029120026":["f8b4","f8b4"],"2266992835335104568":["c1d2","c1d2"],"3961095421515809596":["b4d2","b4d2"],"4056912953296602773":["d1d2","b1d2"],"14177402040852527728":["c8b7"],"7355413720908220473":["f8g7","f8g7","f8g7"],"15469742090136781715":["g2g3","g2g3","g2g3"],"18034993710375426987":["e8g8","e8g8","e8g8"],"17357372752923537984":["f1g2","f1g2","f1g2"],"4977011897105705529":["b8c6","d7d6","d7d6","d7d6","c7c5"],"4174778872161340130":["e8g8"],"3694964849762263817":["e2e3"],"1399385826846608101":["c4d5","c4d5","c4d5","c1f4"],"2107972918480117496":["e6d5","e6d5","e6d5"],"2474129824147529267":["c1f4","c1f4","c1f4"],"17942685573313705040":["c7c6","g8f6","c7c6"],"1542913369361919151":["a7a6"],"14384064530573710451":["f1d3"],"10562022879105684992":["c8g4"],"8876220209127528156":["c2c4","g2g3","c2c4","c2c4","e2e4","c2c4"],"17801942509425737179":["g7g6","b8c6","b8c6","g7g6","b8c6","g7g6","b8c6","g7g6","g7g6","g7g6","g8f6"],"2213914215225274256":["d2d4","d2d4","e2e4","e2e4","e2e4","e2e4"],"15820099398659572434":["c5d4","c5d4"],"9552964532197780244":["f3d4","f3d4"],"16358956200932178234":["b8c6","f8g7"],"5254424368862059577":["e2e4","e2e4"],"10154625774945919283":["g8f6","f8g7","g8f6","g8f6","g8f6"],"2448718151882490027":["g2g3","d2d4"],"944950454567442579":["c8b7"],"13658691901887657736":["f1g2"],"611724940745994097":["g7g6"],"16269565695244041530":["d2d4"],"2665062695865143416":["f8g7"],"3806636502397750207":["b8d7","g8f6","b8d7","g8f6","c8g4","g8f6","b8c6","g7g6","c7c6","g7g6","b8d7","g8f6","g7g6","g7g6","g7g6","g8f6","g8f6","g8f6","g7g6","c7c6","g7g6"],"518082074563634803":["d2d4","d2d4","c2c4"],"13980782159365262129":["g8f6","d7b6"],"11091103339514923687":["f1g2"],"3178310726032970462":["e7e6"],"6515673973305426572":["e1g1"],"2601156170698922396":["f8e7"],"13532037642370637389":["e2e3","e2e3","e2e3","e2e3"],"1650065928108955369":["b8c6","b8c6","b8c6","b8c6"],"13622244118208541674":["b1d2","b1d2","b1d2","b1d2"],"16457655764111383514":["c8g4","c5d4","c5d4","d8b6"],"5650996174306987773":["d1a4","c4d5","d1a4","c1g5","c1g5","d1a4","c4d5","c4d5"],"11297528547013704838":["b8c6","b8c6","b8c6"],"12493732001516414304":["e1g1"],"15210868416482328176":["f8d6"],"8597285622584569684":["c2c3"],"114077775925207250":["a7a6"],"13951024014489984278":["f1g2"],"8389041798700549487":["g8f6"],"3424400257357251833":["b1c3","d2d3","b1c3"],"15281541772034672160":["c7c6","c7c6"],"5073336495522928027":["e2e3","d2d3"],"16953059845013298495":["d7d5"],"6325323274765422308":["b8c6"],"18169149150866107367":["c2c4"],"8072235895181885664":["e7e5"],"18049969418437179606":["b1c3"],"137719532452985359":["g7g6"],"16743561478517401668":["f1g2"],"6750111929703624765":["f8g7"],"18410899902287247764":["f6d7"],"3992598035876427267":["f1g2","f1g2","f1g2","f1g2","f1g2","f1g2"],"9410462460575215226":["c7c6","h7h6","c7c6","d7d5","f8c5","f8c5"],"1216605564662660545":["g1f3","d2d4"],"14665599069194116348":["e5e4"],"16465320529798660874":["f3d4"],"11161903138594634693":["d8b6","d7d5"],"14656811850227867239":["f1g2","f1g2"],"9121856070887441950":["d7d5","d7d5"],"6111585699469206356":["g2g3"],"8662125853069540204":["g7g6"],"10485047107308775719":["f1g2"],"2626299761695502686":["f8g7"],"11633149873068302336":["d2d4","d2d4"],"7225022326659927362":["e7e6","e7e6"],"13093855173043670496":["d7d6"],"8015799644742109737":["f1g2","f1g2","f1g2","f1g2","f1g2","f1g2","f1g2","f1g2"],"15766526374172660304":["e7e6","e7e6","e7e6","c7c6","e7e6","g7g6","c7c6","c7c6"],"12446267130050052610":["e1g1","e1g1","e1g1","e1g1"],"15190497741285546258":["f8e7","f8e7","f8e7","f8e7"],"14268264370887292291":["c2c4","c2c4","c2c4","c2c4"],"14371614972058953147":["d7d5","d7d5","f8b4","d7d5"],"4762584887609142173":["e2e3","e2e3","d2d4"],"17236783251411542841":["d5c4","c7c5","c7c6"],"4409976455484100058":["f1c4"],"15659809043250350443":["a7a6"],"1184419048070293359":["a2a3"],"15849249004268497199":["b8c6"],"8078911398940412972":["b1c3"],"10054593755598556917":["a7a6"],"12816688320095527592":["f8g7"],"99707742808631554":["g2g3"],"3279888162815553850":["d7d6"],"1458628110702966871":["f1g2"],"11659315809816453166":["g8f6"],"18023406813580288440":["e1g1"],"9535662155173280424":["e8g8"],"16886235095606363151":["g7g6","b7b5","g7g6","g7g6","g7g6","g7g6","c7c5","d7d5","b7b5","g7g6","b7b6","g7g6","b7b5","g7g6","g7g6","g7g6","g7g6","g7g6"],"280250126274238020":["f1g2","b2b3","c2c4","c2c4","c2c4","f1g2","f1g2","f1g2","d2d3","b2b3","b2b3","f1g2"],"13119976907170401853":["f8g7","f8g7","f8g7","f8g7","f8g7"],"481806867144607127":["e1g1","e1g1","d2d4","e1g1","e1g1"],"8708488327353622151":["c7c5","e8g8","e8g8","e8g8"],"11433252215927297530":["d2d4"],"6601782249534945464":["c5d4"],"8600491570260976469":["b8c6"],"15904124972523849302":["c1b2"],"2074513888122542777":["f8d6"],"7142754967990650166":["d5c4","d5c4","d5c4","d5c4","e7e6","d5c4","d5c4","e7e6","e7e6","d5c4","a7a6"],"12764908881138920405":["a2a4","a2a4","a2a4","a2a4","a2a4","a2a4","a2a4"],"15775949045825043995":["e7e6","c8f5","c8f5","c8f5","c8f5","c8f5","c8f5"],"4394521992051511375":["g7g6","g7g6","g7g6"],"15368578999744774660":["f1g2","d2d3","f1g2"],"6969331769648421501":["f8g7","f8g7"],"14989166926028788183":["e2e3","e2e3","e2e4"],"9018868737199397235":["d7d6","h7h5"],"3200327134666644710":["g2g3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3","g1f3"],"6584639954804327752":["f1g2","f1g2"],"17190649189694512433":["d7d5","b8c6"],"7780907679434494743":["c4d5"],"7336871228675933962":["f6d5"],"18032333533437385302":["b1c3"],"119538276721023119":["d5c7"],"3310964812804139346":["g8f6","g8f6"],"8510919296283076804":["d2d4","a4a5"],"12910117725941089670":["d7d5"],"3910199521298418592":["g1f3"],"17088694695678405277":["c8f5"],"7943880008633049872":["f3h4"],"6885319442739667383":["f5e4"],"377068259758802436":["f2f3"],"7749142054192900469":["e4g6"],"12669043395673113771":["g7g6"],"5072505099223652064":["h2h4","f1c4"],"15764486190506061726":["d7d5"],"6894050872511062456":["h4h5"],"5302632488362973499":["f8g7"],"17954305327056147089":["e4d5"],"16065901024643813527":["d8d5"],"905883039520869557":["g1f3","g1f3","g1f3","e2e3","e2e3"],"15561831331126521224":["e7e6","e7e6","e7e6"],"11637663263867911642":["e2e3","g2g3","d2d3"],"913117634158540158":["h7h6","h7h6","h7h6"],"11410785278176951403":["c2c4","c2c4","c2c4"],"1331059863101495148":["g8f6","g8f6","g8f6"],"15102658631824444578":["f8e7","b7b6","f8e7"],"14220995355067654195":["b2b3","f1d3"],"5331557967257193310":["e8g8"],"15751350905480316386":["d7d5","d7d5","d7d5"],"6913632517907729348":["d2d4","d2d4","d2d4"],"11152891313785136774":["c7c5","g8f6","g8f6"],"8986605547175603707":["e2e3"],"15103829138352072031":["b8c6"],"8824324572292235356":["c2c4"],"17750325871483644763":["c5d4"],"12198959880001880733":["e3d4"],"13824649985945815439":["g8f6"],"10189379131966383586":["g8f6"],"15459105586814657652":["d2d3"],"8659583659978301344":["h7h6"],"10480934859288532583":["c1b2"],"5840932032535480968":["g7g6"],"13311369359314507971":["g2g3"],"10690912910569505019":["f8g7"],"2657683503715830609":["f1g2"],"10453380772009612072":["e8g8"],"11221056959389235907":["g1f3"],"4674137369362275326":["b7b6","d7d5","d7d6"],"13918712725083105040":["e2e3","g1f3"],"7783471162915696564":["e7e6"],"1906012175482535910":["g1f3"],"13913711687385669339":["f8e7"],"15409942298434826826":["c2c4"],"6483076542518188365":["b7b6"],"16311872489482719513":["c7c6"],"8081010747029930658":["g1f3"],"17343182353509999113":["b1c3","b1c3"],"808471813073658064":["e7e6","e7e6"],"9029636874796888194":["b2b3","d2d4","b2b3"],"17431699890014264303":["f8d6","f8d6"],"15926658233992460174":["f6e4"],"6967514058475704477":["d1e2"],"17777706901899332377":["d8e7"],"13487694934723051797":["d7d5","e7e6"],"4487776562310319923":["g1f3"],"16511082405871939086":["g7g6"],"907324333204798533":["d2d3"],"11687884992543573905":["f8g7"],"1337438038055849019":["e2e4"],"15458752539388457265":["c7c6"],"4968148137566429834":["e4e5"],"2705395182386562286":["g8h6"],"12752189046197073326":["b2b3"],"4350055075989249731":["g7g6"],"15414365016223566984":["c1b2"],"1555493762626113639":["f8g7"],"11901330338433833933":["g1f3"],"9101467075465706224":["e8g8"],"8423426349427987227":["d2d4"],"12813631581408935513":["c7c5","b7b6"],"16762210678428747592":["g8f6"],"12930222902598108894":["g2g3"],"11499900387262783206":["d7d5"],"1944983763343151296":["g1f3"],"13950203980653982205":["c7c6"],"6044949404104663622":["f1g2"],"16578834388980333119":["c8g4","c8f5","c8g4"],"16483192484293702869":["h2h3","h2h3"],"879272324792401191":["g4h5","g4f3"],"1091431046758860302":["h7h6"],"11372364934369333019":["b1d2"],"14176339614403192619":["g8f6"],"189863604688905843":["d2d4","d2d4"],"14382149045311818545":["g8f6","f8g7"],"11275215417354914471":["c2c4"],"1178258146812830112":["f8g7"],"11594022719426587146":["g1f3"],"8899907579297958711":["e8g8"],"8154399885446439644":["f1e2"],"192173213292177535":["d7d6"],"1895416814703199789":["c8f5"],"11048655906665058208":["c2c4"],"1546750240231875751":["e7e6"],"7133942702011839733":["b1c3"],"10995472201087809068":["f8b4"],"10767872785823987445":["e7e5"],"2240298325364936387":["e2e3"],"12842684795634320999":["d7d5"],"3985760246605563969":["d2d4"],"17466485999910872323":["e5e4"],"15952683466601052917":["c2c4"],"5854906237410284018":["c7c6"],"9967485564787079474":["e2e3","b1a3","e2e4"],"2845956095601029526":["a7a6"],"17692721051546995018":["f1g2"],"4645761477849190707":["c8b7"],"17359485366271721128":["e1g1"],"10276155258652878264":["e7e6"],"12252316447590016875":["c8f5"],"2959306314773327590":["c2c4"],"11903592494516782561":["e7e6"],"15223851739172001203":["b1c3"],"2941665146888378218":["h7h6"],"10655875146498484814":["c4d5","c1f4","c1g5","c4d5","c1f4","c1g5"],"11370425984906608211":["e6d5","e6d5"],"10323875791646555433":["b7b6","f8g7","f8g7"],"16441673867964664498":["f1g2"],"5898778969023877835":["c8b7"],"16234602254267858256":["e1g1"],"11473376932965901888":["f8g7"],"11269670497880521002":["d7d5","d7d5","e7e5"],"1805885792066369292":["c4d5","e4d5"],"1667827485565543185":["c6d5"],"7229965200993971883":["e4e5"],"372930113340197071":["c8f5"],"9670777031563231554":["d2d4"],"4828052121139833856":["e7e6"],"15695800775901642752":["e4e5","e4e5","e4e5","e4e5"],"13306933313029875300":["f6d5","f6d5","f6d5","f6d5"],"8987890325611041745":["d2d4","c2c4","d2d4","d2d4"],"13370704657651760787":["d7d6","d7d6","d7d6"],"9238254383505225726":["g1f3","g1f3","g1f3"],"6579815801524606659":["d6e5","d6e5","d6e5"],"7022575765118916682":["f3e5","f3e5","f3e5"],"16084262506736958844":["c7c6","c7c6","c7c6"],"17245239860672007180":["e7e5","e7e6","g7g6"],"7290041377405465658":["f1c4"],"475369634651251356":["d7d6"],"4549282462095469553":["d2d3"],"10486226693638705189":["f8e7"],"13028435774397737747":["c1f4","c1g5"],"8041928989072621936":["d5c4"],"10358341974227565891":["f8g7","f8g7","f8g7","f8g7","f8g7"],"4574536354945169129":["b1c3","b1c3","b1c3","f1g2","f1g2"],"14189466048587068464":["d7d6","e8g8","e8g8"],"18253522844669918557":["d2d4"],"4063555261012250655":["e8g8","e8g8"],"14286228776054079623":["e2e3"],"7741586413068830755":["b8c6"],"14862988756958748123":["f1g2","f1g2"],"8913700065840416162":["d7d6","d7d6"],"8533759476631296633":["g1f3","g1f3"],"12469040141226031940":["f8g7","f8g7"],"2141127676903747822":["d2d4","d2d4"],"15601594928475659692":["c5d4","c5d4"],"9772043561630938218":["f3d4","f3d4"],"16143461300231874116":["b8c6","b8c6"],"8424873385392607210":["e2e4","c2c4","c2c4"],"12748605318225244896":["f8g7"],"22545808406575434":["d2d4"],"11424227167514138014":["e4e5"],"18442826417899189242":["f6e4"],"14265812034004958720":["f1d3"],"10446092550289456243":["d7d5"],"13843941957039547654":["e7e5"],"5361402959782616368":["g1f3"],"10449313622293511181":["d5d4"],"8317586724247787242":["c3e2"],"14084230550699388490":["f8d6"],"15477152405633555519":["b1c3"],"3269328273745403622":["g8f6"],"8552555920704806768":["f1g2"],"14069503855929575177":["c8f5"],"11863996697488666748":["b1c3","b1c3","b1c3","b1c3","b1c3","b1c3"],"6859679879606743717":["d5d8","d5d6","d5a5","d5d8","d5d8","d5e5"],"9286437958086272902":["d2d4","d2d4","d2d4"],"5031965220359539396":["g8f6","g8f6","c7c6"],"2178358212411837266":["g1f3","g1f3"],"14222360712041900655":["c8g4","c7c6"],"8093170620052448860":["e7e5","e7e5"],"18025868091757387370":["c2c3","c2c3"],"10156258307762770412":["d6d5","b8c6"],"14480433726955508654":["d4e5"],"6082439513885467870":["d5e4"],"15408907772299972739":["e5d4"],"5668173173783856278":["d1d4"],"3683507698363067307":["b8a6"],"11035108237092428894":["f1b5"],"12320236519346737117":["c6d4"],"9045243694846922620":["e1g1"],"284379899429557356":["a7a6"],"5239273496979456045":["e8g8","e8g8"],"5124375476920707994":["f8g7"],"17843628884941283376":["f1e2"],"9521117687161711251":["e8g8"],"10270779598294713208":["e1g1"],"17364851659374352488":["c7c5"],"12860315101629505184":["g1f3","h2h4","c2c4","h2h4","h2h4","b1c3"],"7574465805645103005":["e7e6"],"2263651130702770127":["f1e2"],"7848253855662237036":["b8d7"],"3891155747608790357":["f1g2"],"9507110856603524396":["b8d7"],"12687609549800354016":["h2h3"],"6404307885611284754":["g4f3"],"16831533731183743842":["g2f3"],"4500805976764476559":["e7e6"],"875427811385216882":["f1g2"],"13390898829204707083":["b8c6"],"1313889369385431560":["e1g1"],"7804355936177531160":["g7g6"],"9654300350309284691":["d2d4"],"4670189582704449041":["c5d4"],"11988797974647701855":["d2d4"],"7157886216598563869":["c5d4"],"4344656023885301211":["d1d4"],"4754752711744826086":["b8c6"],"16866436370794647525":["d4d2"],"6817085568771887248":["g7g6"],"4587579720298564820":["b1c3"],"14140532973527371277":["c8b7"],"8407388541882900886":["d4d5"],"14890386934629677003":["e7e6"],"13317911794121615257":["a2a3"],"8308731269552155097":["f8d6"],"4343829375650309854":["c7c5"],"9791696266898791730":["e1g1"],"17984681820644869666":["g7g6"],"10211154712978626300":["c8a6"],"4802277528893166594":["b2b3"],"14844846977988558703":["d7d5"],"5433979670500323657":["c2c4"],"14360502138269997646":["b5b4"],"16683487961157282048":["f7f5","c8d7"],"4616494818121189922":["g8f6"],"2017367881136851892":["f2f4"],"16638931914436179517":["d6d5"],"11740830029161657471":["e4e5"],"14093689895108563483":["d5d4"],"2448746704863394044":["e5f6"],"3827879746843781785":["d4c3"],"14493137427393433784":["g7g6"],"2347964100089987827":["g1f3"],"18154370870282134478":["f8g7"],"5426122491013261412":["a2a3"],"9282275685439039012":["d7d6"],"5821864192058177866":["d2d4"],"10808152060893631496":["c7c5"],"8327037685117984629":["d4c5"],"895291048553859097":["e7e6"],"8798701364696930379":["c1e3"],"654147478903014341":["d8c7"],"1084453317329816772":["b1c3"],"17625919237787913757":["f8c5"],"3376178387797714910":["h7h5","h7h5","h7h5"],"3712098727774127785":["c1g5","f1d3","f1d3"],"17716687141882486113":["d8b6"],"1015385342199689752":["d7d5","d7d5","g8f6"],"10051327942189333566":["d1a4","d1a4"],"6474417928624787013":["c8d7","f7f6"],"13481594338249219700":["e4d5"],"11297056269433330802":["c6d4"],"5561281087551058158":["g2g3"],"7046753455775672534":["d7d6"],"6373031692893659579":["f1g2"],"17114151315368737218":["g8f6"],"13145894096774903892":["f2f4"],"5438628760599201245":["g7g6"],"10776206281345784122":["d2d3"],"4262662896605094638":["c8e6"],"7636679946761621061":["e2e3"],"14183996047290582753":["a7a6"],"1594376190371315261":["g1f3"],"14791140885677104896":["b8c6"],"7397558928732322307":["d2d4"],"11789376044800333633":["g8f6"],"11655057867264672785":["e7e6","e7e6"],"15548932044662527043":["g1f3","g1f3"],"3150446019692949450":["b8c6","b8c6"],"9247430539336927945":["d4c4","d4c4"],"8513933480973391558":["g8f6","g8f6"],"3299350247624611664":["b1c3","b1c3"],"15446991798829946249":["f8b4","f8b4"],"6195100837149750158":["d1a4"],"9754187855277048309":["f8e7"],"10633462453756521828":["f1b5"],"11551579576518377191":["d7d6"],"3237006084650278028":["c1b2"],"17046350985892921443":["b8c6"],"5151296664016168288":["e2e4"],"9473911485199947882":["g8f6"],"15606147780369471996":["e4e5"],"13397428537430015896":["f6d5"],"9078665021142075949":["g1f3"],"12004636495351016208":["e7e6"],"762862098804734081":["e2e3","g1f3"],"12086387870647119909":["d7d6"],"4676423091427569607":["c1b2","c1b2"],"9266459713865118504":["b8c6","b8c6"],"3131352909882873387":["e2e3","e2e3"],"9680925363956080271":["d7d6","g8f6"],"13826921885961787362":["g1f3"],"7244027648789575391":["g8f6"],"4569394616596412233":["c2c4"],"12919243028954094670":["f8e7"],"15976443425420582681":["f1b5"],"17179379941855530138":["f8d6"],"5346061429703171518":["b1a3"],"5609188085684105840":["d6e7"],"15304650626175993006":["e2e3","e2e3"],"8739314353770171402":["c7c6","e7e6"],"16947790821098348465":["f2f4"],"1595638729716818488":["c8g4"],"1510696859824647378":["g1f3"],"14957578322517888495":["b8d7"],"10829353106878222768":["d5c3","d5c3","d5c3","d5c3","d5c3","d5c3","d5c3","d5c3","d5c3"],"15436306254104902190":["h7h6","h7h6"],"4958337460469003067":["g5h4","g5h4"],"2179994444495475104":["c7c5","c7c5"],"13047117097092280288":["c8f5"],"3893857007009621613":["c4d5"],"4045175419513041520":["c6d5"],"5427953371646361546":["d1b3"],"8190452799157753644":["d8c7"],"4102851056581246315":["f7f5"],"12438704495617396917":["g2g4"],"1140015450124565928":["g8f6"],"16616441824560081042":["f8g7","f8g7","f8g7","f8g7","f8g7","g8f6","f8g7","f8g7","f8g7"],"6207447357817037624":["b1c3","e2e4","e2e4","b1c3","b1c3","b1c3","b1c3","b1c3"],"12516155207463051745":["e7e6","c7c5","b8c6","b7b6","d7d6","c7c5","d7d6"],"15836242782543884723":["e2e4"],"2288584666512329913":["g8e7","g8e7"],"2417609591557064452":["g1f3","g1f3"],"18080263564487059001":["d7d5","d7d5"],"8918183597645367230":["f8g7","d5b6","f8g7"],"17092763943920584533":["b1c3"],"1631053602592081292":["g8f6"],"5570001015339811866":["g2g3"],"7037478223280790562":["d7d5"],"16483182594513867268":["f1g2"],"5850215780700605053":["c7c6"],"7048347983699966350":["g7g6","g7g6","g8f6","g8f6","g8f6","g8f6"],"9828139160996244421":["c2c4","c2c4"],"308231980955648194":["f8g7","f8g7"],"13041073137289071464":["b1c3","g2g3"],"5722995744145902001":["b8c6","g8f6"],"16465708536101481650":["d4d5"],"6812171621027330799":["c6e5"],"4631605703862573792":["e6d5","e6d5","e6d5"],"1796539845786600646":["f8g7","f8g7","f8g7","f8g7","f8g7"],"12128944549807902572":["c4d5","c1g5","h2h4","g2g3","h2h4"],"11995391500708731761":["f6d5"],"1705052873394744495":["d1d2"],"16943909939917000778":["d7d5"],"4360657307224062430":["e8g8","e8g8","e8g8"],"3903713781130514485":["f1g2","f1g2","f1g2"],"9501720539383269452":["b4c3","d7d5","d7d5"],"10572558241368800699":["g2g3","g2g3","a2a3","a2a3","a2a3","a2a3","g2g3"],"13717963320483227011":["c8b7","c8a6","c8a6"],"1067272237275351576":["f1g2"],"13492666890493022817":["c7c5"],"282345334740722311":["f1g2"],"13122140124418177790":["d7d5"],"3706769256332670168":["g1f3","d1a4"],"16723547711041328613":["f8g7"],"16273264397366078729":["e2e3","c4d5","c4d5","c4d5"],"5547164864471606701":["d5c4"],"11456388066812709710":["f1c4"],"8835208544089346047":["g8f6"],"9008370009040937058":["g8f6","g8f6"],"2804084774225879540":["h2h3","h2h3"],"14851114002090780678":["a7a6","b8c6"],"2084656952064053466":["g2g4"],"13808935467626167751":["b8c6"],"11102373440004537386":["f8e7","b8d7"],"10295226814722216123":["e2e3"],"2590340062114191391":["e8g8"],"7346524800927583493":["d2d4"],"11583599949550182471":["c5d4"],"7928866389593363620":["f6e4"],"7695041882494837855":["g8f6"],"4493074376072656295":["e7e6"],"5200929039796856309":["b1c3"],"12950788770151032620":["g8e7"],"6599911214915574848":["d5d4","d5d4"],"13401289890345776807":["d2d3","f1d3"],"1683838233425169779":["b8c6"],"14417759951416040107":["g8f6","g8f6","g8f6"],"10662299314153052989":["d2d3","d2d3","d2d3"],"4421650179806683369":["d7d6","a7a6","d7d6"],"9571191645666686131":["g8f6","g8f6"],"5239685126662865387":["e1g1","d2d3","d2d3"],"3945824976016284411":["c8f5"],"13104080075068117878":["c2c4"],"4177251666073630833":["e7e6"],"8246704760209913708":["d2d4","d2d3","d2d3"],"4379480496532827428":["b1c3"],"14362000017084231677":["f8g7"],"8641333852254659671":["c1e3","h2h3"],"352570181723652057":["d7d5"],"9335599887789339135":["e4e5"],"16210601716859672475":["d8b6"],"6137015549842024508":["e2e4","e2e4","e2e4","e2e4","e2e3"],"10460738682303370550":["f8b4","f8b4","b7b5","b7b5"],"227831007151550113":["c7c5","c7c5"],"16526164127598601692":["c1g5","c1g5"],"2522839876464524820":["c5d4","c5d4"],"8943119395894319058":["d1d4","c3d4"],"120260669344725231":["f8e7"],"7313678793504184995":["d4d5"],"16102386612620053758":["g7g6"],"3946129726249557685":["h2h4"],"12277063537834698699":["h7h5"],"13204125601627702972":["g1f3"],"7807718590388332417":["f8g7"],"15917597975867075627":["b1c3"],"2842250360694386418":["d7d6"],"11236098512492395629":["f8e7"],"10314043587264565500":["b1d2"],"4671009193148257610":["e2e3"],"17149567394359811566":["e8g8"],"17308665140956147925":["c8f5","c8f5","c7c6"],"8299521355511347544":["d1b3","d1b3"],"5320001352095371710":["d8c8","d8c8"],"11239669805999966729":["c1g5","c1f4"],"6746677109059817921":["e7e6"],"4661140552403591274":["e7e6"],"14751640045278732075":["e6d5","e6d5"],"17558553850621845472":["d2d4","d2d4"],"3934364971687277218":["c7c6","c7c6"],"11881412774500770073":["c1f4","c1f4"],"9203008490221128570":["c8f5","g8f6"],"11814626763508287225":["d7d5"],"2783187622198528223":["d2d4"],"13124472743803378007":["f8d6","f8e7"],"13544322802740964724":["b8c6","g8f6","b8c6","g8f6","f8b4","g8f6","b8c6"],"1160452292026599543":["g1f3","g2g3","g2g3"],"14663207741534693706":["g7g6"],"2506955346877184769":["g2g3"],"1021408759470531385":["f8g7"],"13731748407095158931":["f1g2"],"819895999586414826":["g8e7"],"3814890729227597330":["d5c4","c7c6"],"13580220346300330212":["d7d5","f8b4","f8b4"],"4115305312202211010":["c4d5"],"3978397750920817375":["f6d5"],"7191058544331548402":["g8f6"],"4045905169384286052":["a2a3"],"17562279837251250468":["b4c3"],"6692609110093764824":["b1c3","d2d4","d2d4","b1c3"],"12067382162308733441":["g8f6","g8f6"],"18201791376041335703":["g2g3","d2d4","g2g3","g2g3","d2d4"],"15023862741886665647":["g7g6","g7g6","g7g6"],"4164467133447541220":["f1g2","f1g2","f1g2"],"10104683067026426269":["f8g7","f8g7","f8g7","f8g7"],"6102947620896710336":["d4e5","g1f3"],"14425022755095751088":["d6e5"],"17441026472090724153":["d1d8"],"11005337226329677034":["e8d8"],"2024041864677819526":["e2e4","g2g3","e2e4","g1f3","d2d4","g1f3"],"15569465161015409036":["d7d5","d7d5"],"6736250373758056362":["c4d5","e4e5"],"6003689505939186615":["e6d5"],"7801846507062895484":["e4e5"],"953827380395574552":["f6e4"],"10092835895200817065":["g1f3"],"6301688388825644692":["b8c6","b8c6","b8c6"],"4155959870626952917":["c5d4","c5d4"],"7400070934717734675":["f3d4","f3d4"],"82762524100562237":["e7e6","e7e6","e7e6"],"1537939680406787428":["e2e3","e2e3","e2e3","c1g5","c1g5","c1g5","e2e3","d1d3"],"13293105817214541248":["b8d7","b8d7","b8d7","b8d7","b8d7"],"2570528886522758608":["d2d4","d2d4","g2g3","d2d4","e2e4","d2d4","b1c3"],"14541122394286055332":["b1c3"],"16453258033812541024":["b1c3"],"11084980654849897882":["c5d4","c5d4"],"14324676191277276252":["f3d4","f3d4"],"11628052640300158578":["g8f6","g7g6"],"18064237932811128804":["b1c3"],"14811560053170138787":["e2e4"],"687421457228288937":["d5c3"],"8446443318710417606":["c3d5","f1g2"],"13052106126109558675":["e5e4"],"6033295502709863056":["e2e4"],"9441242625089266973":["d7d5","d7d5"],"462791255251528507":["d2d4","d2d4"],"11274383719688649544":["b2c3"],"15538476942497010696":["b7b6"],"3153365364445755383":["c7c5","c7c6"],"14822138818857548938":["f1e2","b1d2"],"13705108152610813481":["b8c6"],"1576131925007717162":["e1g1"],"7758277839057520698":["c8g4"],"2214322346111028096":["g7g6","f6e4","d7d5","d7d5","d7d5","e7e6","e7e6","d7d5"],"17802317192162171339":["g5f6"],"5922656803626780459":["e7f6"],"16382022993701633029":["e2e3"],"5653673052685366433":["d7d5"],"14636632522999872135":["g2g3"],"16715524509809107647":["h7h5"],"13852734423407412272":["c7c6","h7h6"],"5927899840427497355":["e2e3"],"18404351086368988975":["d8b6"],"4077619800381245941":["b2b3"],"12984156788665328280":["g8f6"],"17284473678450778894":["f1d3"],"13462218721710121341":["g7g6"],"2644341882991265402":["g5f4"],"13936954140823155416":["d7d5"],"4899955872617741566":["f2f3"],"3076457543698142095":["e4f6"],"1205145324624764533":["b1c3"],"16946642887990959276":["c8f5"],"810264484518438534":["c3b5","e2e3","e2e3"],"18440243791773085820":["f8b4"],"3938821192148547678":["c2c3"],"4644189341868586968":["b4a5"],"8164933841884850045":["b2b3","d1c2"],"18296379558272814096":["f8b4"],"11227746985974695334":["e2e3","e2e3","e2e3","e2e3"],"3962528682465266946":["c7c5","c7c5","b8d7","c7c5"],"15027504225638975103":["g5f6","g5f6","b1d2"],"8481230999227390111":["g7f6","g7f6"],"11020295566650095990":["d4c5","d4c5"],"16648918037954686490":["d8a5","e7e6"],"16207464984025958114":["g7g6","d5e4"],"673333032102171817":["e4e5"],"7503346822637859533":["c6c5"],"16500103564954365698":["c2c3"],"10531347073632231556":["b8c6"],"17482868290185349094":["e1g1","d1a4"],"10154169242887625974":["a7a6"],"1031987402074780453":["d7d5","f8b4","d7d5","d7d5"],"10031901243314289923":["f1g2","f1g2","f1g2"],"4523965308953814394":["f8b4","c7c5","d5c4","f8e7"],"17836844409188396376":["b1d2"],"12603195043477161320":["e8g8"],"572678068748918876":["c4d5"],"709339373801419841":["f6d5"],"10823959886589896989":["g1f3"],"5571659575509305376":["b8c6"],"17063826403342389432":["d7d5"],"7612422163856088734":["c1b2"],"12238579978831086193":["c8f5"],"3080816374001060860":["e2e3"],"9794203948680594264":["e7e6"],"17409775589693805322":["g1f3"],"3098228457764631095":["g8f6"],"8147340117769155489":["c2c4"],"18244844670190672038":["c7c6"],"10735739470805148831":["e2e3","d1d2"],"4165900682033146939":["f8g7"],"9902427324876547985":["h2h4"],"1571722212511850223":["h7h5"],"296038295208015423":["b1c3"],"18414275358224004326":["g8f6"],"12663735689042742792":["e2e3","e2e3"],"185740712661993132":["c7c5","c8f5"],"16492801544749370833":["g1f3"],"4577591714292346092":["c5d4"],"11184831368006974889":["e2e3"],"3897234824740222221":["c5d4"],"7569275925781044427":["e3d4"],"9194965963021326297":["c8g4"],"368945019048648910":["g1f3"],"16031175834898072051":["e7e6"],"12109580495727071649":["b1d2"],"17393859640217975185":["f8e7"],"7551736405547473874":["e2e4","e2e4"],"12450804840027285208":["h7h6","h7h6"],"4484862224776441805":["g5f6","g5f6"],"11169604098641129773":["d8f6","d8f6"],"10789259845325373869":["d1d2","b1c3"],"13664881304552381965":["d7d6"],"5965448675228519717":["g5h4"],"596427012917964734":["c7c5"],"17165257343142405315":["d4c5"],"10508054940406477743":["b8c6"],"4195600759119074988":["c2c3"],"5540338150210459946":["a7a5"],"667811081964398660":["d2d4"],"8969780493575786217":["b4c3","b4c3","b4c3","b4c3","b4c3","b4c3","b4c3","b4c3","b4c3","b4c3"],"13623536791883861557":["c7c6","e7e6","c7c5"],"3416661359309561230":["d2d4"],"16888292777047765196":["d7d5"],"8013424197968494314":["f1g2"],"15764082644301883027":["g7g6"],"16273101601314621091":["f8g7"],"9304764696275566381":["e8g8"],"10054787024059127494":["g1f3"],"5831364856672303099":["f8e8"],"6141832743812873976":["b1c3"],"12586211807103204385":["b8c6"],"380250798458083618":["f1g2"],"12733593056688390491":["g7g6"],"6413860549599515408":["a1b1"],"743580647833114116":["a7a5"],"10853018297454154738":["c6d5","c6d5","c6d5","c6d5"],"16567905108507745864":["b1c3","b1c3","b1c3","b1c3"],"2196099966225177745":["b8c6","b8c6","b8c6","b8c6"],"12355490758319959703":["c2c4"],"2279345923159933277":["g2g3","d4d5","g2g3"],"3695034493853833573":["c5d4","c5d4"],"7807513866959417507":["f3d4","f3d4"],"850477895896588941":["f8b4","d8c7"],"17677230485592943475":["e2e3","e2e3"],"6376923099843437527":["b7b5","g8f6"],"4069859677407223530":["a2a4"],"6031011752233716516":["c7c6"],"15128391588830190012":["d7d5","d7d5"],"8122209676381345108":["d7d5"],"15938376687770432923":["g8f6","g8f6","g8f6","c7c5","g8f6","g8f6"],"9718286215141536781":["e2e3","e2e3","e2e3","e2e3","e2e3","b1c3"],"3166887472253222057":["e7e6","c8f5","e7e6","c7c5","c7c5"],"6738785071214046459":["g1f3","g1f3"],"5459349525585635996":["d4d5","d4d5"],"17423233044899970241":["d7d6","d7d6"],"14443418138276296108":["e2e4","e2e4"],"898003089584605350":["g8f6","g8f6"],"12924920297910881038":["f8g7"],"279987607397531812":["e2e4"],"14404124000054787502":["e8g8"],"14793576841292043333":["f1d3"],"10971039601080702518":["c7c5"],"7888481367444779554":["b1c3"],"10875802365336219899":["c8f5"],"1583003373283768694":["g2g3"],"4112154176333881678":["e7e5"],"12927941331109599608":["g1f3"],"7497836779283068997":["b8d7"],"12171649718281876772":["c2c4"],"2650928901850865187":["e7e6"],"5962220529132867185":["b1c3"],"12189299540249218216":["f8b4"],"15260068045342131829":["d7d5","f8g7","f8g7","f8g7"],"6245513145779750995":["c4d5"],"6378819801136110670":["f6d5"],"14368845239602839826":["e2e4"],"247532869563956248":["d5b6"],"16621748064517661309":["a6b5","a6b5"],"6958558442278241979":["b1d2"],"4179195780880920203":["f8d6"],"8226102618194734529":["d7d6"],"5445532403459171500":["e5d6"],"742512075211451315":["f8d6"],"7168211905426722271":["e2e4","e2e4","e2e4"],"12068969664109031637":["d7d6","d7d6","d7d6"],"11441115126147021240":["b1c3","b1c3","b1c3"],"459235474400871650":["d4d5"],"13571539652036956863":["c6a5"],"16807681874376143029":["e2e4"],"3262836857988615615":["c7c5"],"4269883630390312678":["e2e3"],"10839300344001132098":["c5d4"],"14515756970826309508":["e3d4"],"16132518958617678998":["b8c6"],"8363149765855736213":["g1f3"],"12640210594408861864":["f7f6"],"11007208202926593263":["g1f3"],"4874470933056910802":["b8d7"],"14808639104992851924":["d4c5","d4c5"],"12853827621759410360":["e7e6","d8a5"],"14129492080303567082":["b2b4"],"7990370365199490254":["a7a5"],"7715349255805780279":["b1d2"],"3638016965250738439":["a5c5"],"3327505926048099311":["c7c5"],"14437551585655365778":["e2e3"],"7309691970329389110":["g8f6"],"4512337360124930464":["g1f3"],"16572092268620417181":["b7b6","d8b6"],"10273466172076572422":["b1c3"],"8432347842328083935":["a7a6"],"3043125145428723641":["e6d5","e6d5","e6d5","e6d5"],"3322996223029026993":["g8f6","g8f6"],"12585156331990059844":["c7c5","b7b6"],"5248563490431533113":["e2e3"],"2451110804684398151":["b1a3"],"2729964337044648329":["c5d4"],"4163333575772504601":["d1d4","d1d4","d1d4","f3d4"],"4931501034417777956":["b8c6","b8c6","b8c6"],"9450189495482432289":["g8f6","g8f6","g8f6","g8f6"],"10065239375779427318":["e4e5","e4e5"],"16922274635096348050":["f6e4","f6e4"],"15050945845569312872":["c3e2","f1d3"],"6972856031988916424":["f7f6"],"3013264061438682261":["g1f3","g1f3"],"17489077436777137576":["d7d6","d7d6"],"14631976546871463109":["d2d4","d2d4"],"1025156669821673863":["g8f6","g8f6"],"6184606099596746769":["b1c3","b1c3"],"12556944886111085256":["c8g4","g7g6"],"12434703460850084898":["d4d5"],"1628248876579076735":["c6e5"],"1057312004632966186":["f3e5","f3e5"],"12749593037265031452":["c6e5","c6e5"],"12457801618882395213":["b1c3","b1c3","g2g3"],"14082730169938660209":["b2b3","c2c4"],"5749276126242643996":["g8f6"],"1452337043595714954":["c1b2"],"15301919667790380389":["f8e7"],"13877601676373709300":["c2c4"],"5527247250328222451":["e8g8"],"266956776937781803":["d7d5","g7g6"],"9334426830560481057":["g1f3"],"6552288073005706780":["e7e6"],"1684873114507344896":["b1c3","g2g3","g2g3","g1f3"],"17075054430513168089":["e7e6"],"11133238819888885387":["g1f3"],"4748440311363440566":["g8f6"],"1876855059930467872":["c1g5"],"15875675276588750312":["f8b4"],"1517945669945515466":["e2e3"],"13275926952590333294":["h7h6"],"16861539253049223264":["b1c3"],"1267517277409864377":["d7d5"],"13611370819187047231":["c7c5"],"6527013327410163778":["e2e3"],"17850543480130664678":["b8c6"],"6010559367374131294":["d2d4"],"10834785819293809948":["g8f6"],"10726563702824604858":["c8f5"],"1712776700696239415":["d4c5"],"7504724884464650843":["e7e6"],"11791405776160892177":["c1b2","c1b2","c1b2"],"7196636832037321214":["f8g7","f8g7","f8g7"],"15232206000079794772":["g2g3","c2c4","d2d4"],"18412142333874383468":["e8g8","d7d6","e8g8"],"17667054339977003911":["f1g2","f1g2"],"3526437374713388222":["d7d5"],"13085857495197913752":["d2d4"],"8119816015802449882":["f8e7"],"7233645537768313675":["f1g2"],"15101468091154484018":["e8g8"],"11967157070014819874":["c7c5","f8b4"],"4639323745067902303":["g1f3"],"11170858556061172834":["a7a6"],"14344864305402329863":["c1d2"],"17194295467120185347":["b4e7"],"14683235497916314041":["f1g2"],"9094306545907853760":["d7d5"],"6624896398103013918":["e6d5"],"8910192306535773924":["c7c5"],"17047813254047851540":["d1a4"],"4481436338042246767":["b8c6"],"14048120630208988130":["e1g1"],"13583288017442986226":["c7c5"],"10961068446876881744":["b8d7"],"12393610339298459292":["f1g2"],"2165898875197712101":["e7e5"],"2548189741011138670":["a7a6"],"17414026550745202866":["e2e4"],"3867468011839634872":["b7b5"],"6423668614710621179":["c8b7","c8b7","c8b7","c8b7"],"16840432448368303200":["b1c3","b1c3","b1c3","b1c3"],"1306647200055877305":["f6e4","d7d5","d7d5","d7d5","d7d5"],"15761155506444449871":["e8g8"],"14993102024005505444":["f1g2"],"15207966320207662764":["b8d7","b8d7","b8d7"],"4289507578016638008":["g8f6","g8f6"],"6947172548135970222":["f1g2","f1g2"],"15391460619120066007":["g7g6","g7g6"],"4336157998433906588":["g1f3","g1f3"],"16666076538595790497":["f8g7","f8g7"],"6338238103706043659":["e1g1","e1g1"],"2990542766415371803":["e8g8","e8g8"],"4526330666880939274":["c6d5"],"5593592281263192240":["c4d5"],"4866972030702005421":["g8f6"],"1757742729665408315":["b1c3"],"16425710936793935842":["f6d5"],"2555961852124705009":["g1f3"],"17878293492450578892":["c8g4"],"8929770519738218960":["f8g7"],"14650446322969588346":["c1d2"],"16347378330055924094":["g8e7"],"9562287217903277908":["e8g8"],"13465896505310666702":["f8e7"],"12550417709803565919":["e2e3"],"216534364065070075":["b8d7"],"15616666926592749063":["c4d5"],"15461719647902562842":["f6d5"],"17219001616376928949":["c2c4","b2b3","b2b3"],"7121793657116144050":["c7c6"],"12342634936926410588":["e7e6"],"15942227171669542670":["f1d3"],"9814340328053884285":["c7c6"],"1894158818580993734":["g1f3"],"13919935536163190779":["f8d6"],"7311002579621455583":["b1d2"],"4331159365205659375":["f7f5"],"17292877480336177428":["e6d5","e6d5","c5d4"],"15053419976288369119":["g1f3","g1f3"],"832199735984625890":["b8c6","b8c6"],"8136211434359834389":["d7d6"],"5283627608733716088":["g2g3"],"7328917973674153536":["b8c6"],"14868727572572894019":["f1g2"],"8910503315673885498":["g7g6"],"10528330527191153009":["c2c3"],"16485105713321948919":["f8g7"],"13382988317823329356":["f1d3"],"17056342738583103039":["c8g4"],"17178848186304440533":["c2c4"],"7080811229635511250":["e7e6"],"16775028439965757007":["e8g8"],"13226285703247598198":["g8f6"],"17033458425060447200":["c1g5"],"3042558708469336104":["h7h6"],"1142913488445854493":["d7d5","d7d5"],"9995268609016143163":["g1f3","g1f3"],"5895985014191127558":["d5d4","g8e7"],"12952305589154502369":["c3e2"],"262591215712808513":["c7c5"],"16561205873581761852":["c2c3"],"10596900000774761146":["b8c6"],"8587121962400022683":["g1f3"],"12416275857963311526":["d7d5"],"2964876051152187264":["c2c4"],"11891407555432449159":["e7e6"],"15236018499883111637":["b1c3"],"2947094377428850188":["g8f6"],"1788265499167208713":["g8f6","d7d6"],"4845597267199587487":["f1g2"],"17784475749573799142":["d7d6"],"14949048146722645387":["d2d3"],"7048542174359445087":["c8g4"],"2408202726623870052":["f1g2"],"10708298652421923869":["e7e5"],"2225777299360504875":["d2d3"],"12702208670047561727":["g8f6"],"8042588479855196091":["d2d4"],"12297127395015777017":["b8d7"],"11046018630697039669":["f1d3"],"14719723777949431110":["c7c6"],"17878103616335005551":["g2g3"],"15347550398006271831":["e7e6"],"11712316668950810373":["f1g2"],"1691843323492226940":["d7d5"],"10525053742649874778":["b2b3"],"2213203423980417591":["f8b4"],"5429394049812265599":["e2e4"],"10330721360114698101":["d7d6"],"13178814774275018264":["g1f3"],"7890415124290432805":["b8d7"],"6796913191026757687":["b8c6"],"7388620841030304986":["f5d3","f5d3"],"11474674154358550204":["d2d4"],"6488302722103411710":["c8f5"],"15641702434685422195":["f1g2"],"7845936859088966154":["c6b4"],"8507976923330354750":["b1a3"],"8211390041587560944":["e7e6"],"18691227922816319":["b7c6"],"14128365455147710741":["b2b3"],"5238928618955461240":["e6e5"],"3659540403779025245":["d7d6","a7a6","a7a6"],"825243925660332080":["h2h3"],"16393110634963384770":["g8f6"],"13289616385942616148":["g2g4"],"1955462085016294729":["b8c6"],"17224869444641282802":["c4d5"],"16222419621160734447":["f6d5"],"9137776154994398131":["e2e4"],"13459266658966793913":["d5c3"],"17751436318404796667":["b2c3"],"8479002227788585186":["g7g6"],"7916861932887397130":["g2g3"],"4699766496602557234":["b7b5"],"2394937192174237199":["d2d4"],"16431850558255766349":["c5d4"],"13481265109034070667":["f3d4"],"15889630142698774693":["c8b7"],"15953914683148966388":["f1g2","f1g2","f1g2","f1g2","f1g2","d2d4","d2d4"],"7536654804820836749":["f8g7","f8g7","f8g7","f8g7","f8g7"],"15576735239376046631":["d2d4","d2d4","d2d4","d2d4","e1g1"],"2098256871186540389":["g8f6","g8f6","c7c6","g8f6","g8f6","g8f6"],"5111785088822208243":["e1g1","c2c4","c2c4","e1g1","e1g1"],"4072888212560887267":["e8g8","e8g8","e8g8"],"9313088108915962772":["d2d3"],"3430194389375846464":["c8b7"],"11460032795114186715":["e2e4"],"6560964360583977681":["d7d5"],"16025804494596953335":["e4e5"],"13771460904859300499":["f6d7"],"15830012709774658062":["g8f6"],"9241866122711780248":["b1c3"],"8923509820541192513":["e7e6"],"1785559229527161455":["g8f6"],"12009145637274966020":["f1g2","f1g2","f1g2"],"1394195214710145149":["c8g4","c8g4","c8f5"],"1264076750726106775":["e1g1","e1g1"],"8070340783002080647":["e7e6","g4f3"],"1839841523362776533":["d2d3"],"13199115696910237185":["f8d6"],"17932174484132317156":["a7a6","a7a6","a7a6"],"3038821838678867768":["b5d7","b5d7","b5e2"],"15396490765721262368":["c8d7","c8d7"],"12282876564116322947":["g8f6"],"17409719744564951829":["d2d3"],"6863340733914759361":["g7g6"],"13153659173599892106":["f1g2"],"250807598223657715":["f8g7"],"6863544668993083731":["e8g8"],"6190435409194004664":["g2g3"],"8723018883278432384":["c7c5"],"16158560768891076993":["g2g3","d2d4"],"14760554554949310905":["b7b5"],"12455949675094779012":["f1g2"],"1813911892155399421":["c8b7"],"18373657166355407918":["g1f3"],"2709120983071638803":["e5e4"],"765196596329519845":["f3d4"],"8428799293721649706":["g8f6"],"3393225620970136508":["f1g2"],"11695446731748830985":["d5c4","d5c4"],"8090905726536024554":["b3c4","b3c4"],"6869458321933460168":["c8f5","c8f5"],"11882163293698118860":["d2d4","d2d4"],"4197064375753553944":["c2c4","c2c4","c2c4","c2c4"],"13122760287804658463":["g7g6","c7c6","g7g6","g7g6"],"6893106328156012884":["g2g3","b1c3","b1c3"],"8309001600574904684":["f8g7"],"14119837443249967814":["f1g2"],"8503814405283203775":["e8g8"],"2623211659597168804":["b1c3"],"16136570886622888573":["d8c7"],"15703949201704095100":["e2e4"],"2155165731226562678":["a7a6"],"14615071739645611508":["c7c6","c7c6"],"10021362128349515124":["d5c4"],"6419106048492020631":["d1a4"],"10065971499381248492":["a7a6"],"6432954792061151536":["b1c3"],"11732276075081837545":["a8b8"],"1091444515420982810":["g2g3"],"2576952069091005986":["d7d5"],"16035821805412188151":["g2f3"],"678111738867499034":["g8f6"],"15762152806555849370":["b8c6","b8c6","f8g7","b8c6"],"8166066903507384217":["d2d4","d2d4","d2d4"],"12999250269742602971":["c5d4","c5d4","c5d4"],"16967354520820314909":["f3d4","f3d4","f3d4"],"10332502667638109406":["e1g1"],"17370690916644007886":["b8d7"],"9983398380307492496":["e8g8","e8g8"],"9233727621364828027":["d2d4","d2d4"],"4324281355407905230":["d5d4"],"16028175376967662377":["e5f6"],"14594876577726769484":["d4c3"],"5175334679989566357":["d7d6"],"9108790940015915768":["d2d4"],"13501161333746884538":["f8g7"],"2322482310245430685":["b8d7"],"5836121462115287206":["b8d7"],"16487317069852372457":["c8b7"],"6079576926985011826":["b1c3"],"12666910117702713515":["e7e6"],"15690001872227815673":["a2a3"],"1101940615639604712":["f8g7"],"13832485817446159938":["f1g2"],"722546185632222779":["g8f6"],"5902190619192086445":["b1c3"],"10647993704193491993":["e2e4"],"6323696623759931667":["c7c5"],"16835727633870544841":["f8b4"],"2370560345965271019":["e2e3"],"10216607761466957647":["b4c3"],"12461976557449432389":["b2c3"],"16655647976099160581":["c8b7"],"6560662821814150812":["d7d5"],"16025507349318625466":["c4d5"],"15009531957694594215":["f6d5"],"235496185496200804":["f6e4","f6e4"],"11988253590241300791":["e7e5"],"3181473544467360001":["d2d3"],"9404112508699480789":["b8c6"],"8124039897036918910":["c7c5"],"10867100760445524739":["d4c5"],"16801962915835399279":["e7e5"],"7157512879808328793":["b2b4"],"14529155171754465405":["g8f6"],"10542884206676941291":["g1f3"],"5272366774370203862":["e5e4"],"12574696633852760878":["f1c4"],"5396430095920158623":["e7e6"],"4365125645612533709":["g1f3"],"17205090483994039417":["f7f5","g8f6"],"8839339263672924583":["b1c3"],"9312525494786308990":["g8f6"],"15767815248510974696":["g2g3"],"17740861099205206736":["g7g6"],"2269920279769886875":["f1g2"],"12281389896312973538":["f8g7"],"11834935413449819021":["c8f5","f8g7"],"2821305619644615168":["d1b3"],"2152414113295278822":["d8c8"],"11937246396690224896":["d7d6"],"11246341731847773805":["b1c3"],"7459105140462754996":["e6d5"],"6177551980135608710":["h7h6","h7h6"],"13054517319904089583":["g1f3"],"7948319668059976914":["f8b4"],"12037967485987781872":["b1d2"],"18330156003581842624":["d7d6"],"14401207197847854509":["a2a3"],"884795161929421805":["b4d2"],"11762710492637635883":["h7h6"],"3587893055794539582":["g5h4"],"7729770678932950693":["d8b6"],"17376024951504351093":["c7c6"],"7160414783743556814":["d1d2"],"5424208348847580014":["b7b5"],"13474247898126465849":["b2b3"],"3915956043048079444":["c8b7"],"14797213354813370000":["d2d4"],"607823839352062930":["g8f6"],"6017187858478154308":["g1f3"],"9864489074285618041":["c7c6"],"1477777416525563943":["e2e4","g2g3"],"6471597513525533644":["a7a6"],"10031849900743680784":["a2a4"],"16208794503061878494":["f8g7"],"17336881118833080534":["d5b6"],"15637472669484290968":["d2d4"],"2032910279279689434":["d7d6"],"2705765354269636535":["e5d6"],"7229204650735859880":["e7d6"],"11620903243637981952":["d2d4"],"7237445422475849282":["g8f6"],"4584284752836128724":["f1c4"],"6641985476369234290":["c8f5"],"7083694728023139182":["e2e3"],"14943810394811001802":["c8f5"],"5510855556985446983":["b1d2"],"1519056183450094199":["h7h6"],"5672828023781823730":["g8f6","g8f6"],"10364729362061426685":["b8d7"],"13561905476598469169":["f1c4"],"16106601822391649431":["f8e7"],"5185729508698796163":["h2h3"],"12611403033077840241":["f8g7"],"9815041381031351605":["h7h6","h7h6"],"13859509050138073881":["a6b7"],"9616301671186587697":["h7h6"],"14253395917565934815":["e2e3"],"7558023840783840379":["c8b7"],"15596869479597349856":["h2h3"],"3495052353892766226":["f8d6"],"16205447975487327116":["b1c3"],"1977724207003093333":["e7e6"],"7855891287981873415":["b5c6"],"4335419873504528700":["b6c6"],"13757287516827433707":["g7g6"],"6296005066199771296":["g1f3"],"13056066372618765331":["d5c4"],"4693656195519617642":["g7g6"],"12148288653223626785":["e2e4"],"7822868424648635691":["a7a6"],"13720102539971382775":["c1e3"],"14803089523757012601":["f8g7"],"9066624332499787219":["g1f3"],"12013336876761382126":["b7b5"],"8456856425285445024":["d2d4","b1c3"],"12711943996706343138":["c6d4"],"9093973924143002622":["f3d4"],"11211272120871182565":["e5d4"],"17063685201497885593":["g1f3"],"15107659714621175145":["c4d5"],"16105905511378411892":["f6d5"],"4642597651426212904":["e2e4"],"9541681475646308642":["d5b6"],"12533891255938558572":["g1f3"],"8544946179458321233":["g7g6"],"15541865527850195327":["g1f3"],"921866471942928450":["g8f6"],"7736838991730581340":["e4d5"],"5512912129689214298":["f6d5"],"4329989041891798515":["e1g1","b5a4"],"4783470570693506787":["d4b5"],"14329074994665887354":["e2e4"],"206062227562605424":["c8b7"],"12847745015156049131":["g1f3"],"7577500930938660310":["e7e6"],"18256204188555550876":["e2e4"],"4133211212896443798":["c8b7"],"9929404975237575181":["f1b5"],"12588699292959353295":["b7e4"],"4626362966819537539":["e1g1"],"4485971909795568019":["b8c6"],"10785143973403917456":["d2d4"],"5807858905298391506":["g8f6"],"15865148858918698625":["g1f3"],"524871813099724732":["g8f6"],"3397419662239306889":["e8g8"],"11452870780179020858":["g8f6"],"2182582530302007247":["b8c6"],"541743563071309747":["f1d3"],"5947294971751597504":["b7b5"],"3642676876882032893":["g1f3"],"16855161030902503872":["c8b7"],"6447403297994307163":["e1g1"],"2813830677262380363":["g7g6"],"4199656925356757939":["b7b6"],"13705363409762456704":["b8c6","g8f6","b8c6"],"1575947102093488515":["e2e3","e2e3"],"13327172984231246119":["g8f6","g8f6"],"2819147800613156079":["g1f3"],"18193261608899745234":["g7g6"],"15683671997466224438":["b5b4"],"13288619780562910837":["e2e4"],"8966002753812743039":["d7d6"],"17872057463700611224":["c7c5"],"16563412174097962262":["e2e3"],"5255785632573349298":["b8c6"],"8256253126411890907":["f8b4"],"7211376143588581527":["e2e4"],"11535118520758197661":["c7c5"],"2726538883089465414":["f8g7"],"10766595008926665708":["b1c3"],"7997752743830997301":["b8c6"],"14199956677979171894":["g1e2"],"15110855009820565960":["e7e6"],"9186265540060674911":["f1e2"],"935807744393294332":["c8g4"],"1022143517953769238":["d2d4"],"14635095388018905684":["g4e2"],"17273291720977705602":["f1g2","f1g2"],"6505216772092703483":["g8f6","g8f6"],"119654101813687149":["d2d3","d2d3"],"12613978996558088377":["c7c5","c7c6"],"5295119073868399556":["c2c4"],"14222250638835574979":["e7e6"],"12909682317781334161":["g1f3"],"7515535109766322604":["b8c6"],"4429069230504516354":["b1d2"],"7212953083761406770":["g7g6"],"10209059774279426425":["e2e4"],"5308284422119169139":["d5e4"],"16187385200892959890":["f1g2"],"6148827936436651243":["g7g6"],"13610253236417173152":["c2c4"],"3513592833947614631":["f8g7"],"9259110184503604749":["b1c3"],"8928930541687502036":["b8c6"],"3709179835817132059":["d2d3"],"11375359000435276751":["f8g7"],"3269992708868632677":["c1d2"],"354662588259900257":["b8d7"],"5644296792896373816":["f6e4"],"17574132468382999339":["f1b5"],"15319981657631832297":["c8b7"],"7209078427531843442":["d2d4"],"11618975913319774768":["e7e6"],"15913167587954199736":["d7d5","c7c5"],"17075208342082106580":["c8d7"],"1609620209148976918":["e8g8"],"2089222427380070141":["e2e3"],"6954548329851027250":["c1b2"],"11601113065890964445":["b8d7"],"10600371278986219025":["d2d4"],"6208557512479941459":["e7e6"],"5714073271189725302":["g8f6"],"1486825216627384800":["b1c3"],"7938788449725956980":["f8b4"],"6358611373085662842":["f1c4"],"4291340499278907612":["f8c5"],"10849350900434510431":["d2d3"],"4049968600820619659":["d7d6"],"112298198275346662":["h2h3"],"16824651212446865684":["c8e6"],"7280829141725148082":["b1d2"],"4351616222352857986":["h7h6"],"14951119925435234684":["c1b2"],"1081398678428083603":["c8f5"],"10094976692288056350":["g2g3"],"11600767487787578406":["b8d7"],"10600716839515478506":["f1g2"],"2805017653108769171":["e7e6"],"10080214519692714899":["c1b2"],"5447376114783125372":["f8g7"],"18096811312567792854":["d2d4"],"4472608431535462804":["e8g8"],"16553243731529393084":["b8c6"],"5644478844755035839":["d2d4"],"10043053765925137405":["c5d4"],"15311853929489859131":["f3d4"],"12964455235037171733":["g8f6"],"1752638346925094070":["f8g7","f8g7"],"12172846048518438684":["f1g2","f1g2"],"10394383426432797168":["d2d3"],"4531896052146287140":["e7e6"],"5229652526471455350":["f3h4"],"8728169633942528209":["f5g6"],"12486060453321321872":["f8g7"],"2160417628328486458":["e2e4"],"15707523239716739888":["d7d6"],"16160819043228062301":["f1g2"],"6176370463717444132":["e8g8"],"4786199666320027367":["d7c6","d7c6","d7c6"],"9935735844190629455":["b8c6"],"2454193489614244684":["g1f3"],"17972712792757561969":["c5d4"],"14475592869083927254":["a4b3"],"10979703634732530484":["f8c5"],"15268106681894600820":["f1d3"],"9722875222198600199":["g8f6"],"6363492045068660394":["g1f3","g1f3"],"9451726011230130071":["d7d6","b8c6"],"13443756001824687866":["c2c3"],"14720904078837762428":["c8g4"],"14886897545652225942":["f1c4"],"12478162135907537200":["d8e7"],"13688841305454170332":["e5d4","e5d4","e5d4"],"2776049323298866377":["f1c4","f3d4","f3d4"],"4986951600073511535":["f8c5"],"16728746525806590188":["e1g1"],"10834826592295035900":["d7d6"],"15235760625082125905":["f1c4"],"12737305340306299127":["e7h4"],"13274309983563628011":["g2g3"],"10723468565931681235":["f4g3"],"2938209910990613140":["b2b4"],"9382101883601471152":["c5b4"],"13681660495994235296":["c2c3"],"14629377292846704166":["b4a5"],"14333378069040301445":["c2c3"],"12695818810506447363":["b8c6"],"2007834759058960128":["g1f3"],"5188085897001180490":["f1c4","f1c4","f1c4","f1c4","f1c4"],"2615602418204527596":["g5g4","f8g7","g5g4","g5g4","g5g4"],"2353711395007384451":["d2d4","d2d4","d2d4","d2d4"],"16546013516754937537":["d8e7","g4f3","g4f3","g4f3"],"2556580720565404303":["c2c3","c2c3","c2c3","c2c3","c2c3"],"6169988691626937609":["b4c5","b4c5","b4c5","b4c5","b4c5"],"10732149639755857990":["e1g1"],"16898976983165164374":["h7h6"],"6577068094911340631":["d2d4"],"11417574208620186901":["c5b6"],"9630112696199530476":["e5d4","e5d4"],"2223374119297668089":["e1g1","e1g1"],"6966639604551846121":["f8e7","f8c5"],"16905080946651992560":["d2d4"],"3436182914828844210":["e5d4"],"13029420206032201895":["f1c4"],"15520099813555928577":["h7h6"],"5013970847750108948":["c2c3"],"3697397515725781138":["c7c6"],"4528616115111931394":["e2e4","e2e4","e2e4"],"18077428099894186760":["e7e5","e7e5","e7e5"],"8117709047550864190":["f2f4","g1f3","g1f3"],"10210109314902742711":["e5f4"],"7222545431478161763":["g1f3"],"13789826602722799710":["g7g5"],"4547719620352724948":["f1c4"],"6678568654263622002":["f8g7"],"17011001208430056152":["e1g1"],"10549203070536190408":["d7d6"],"12376746821892442627":["b8c6","b8c6"],"13177968717836032":["f1c4","f1c4"],"7178004856304204198":["f8c5","f8c5"],"14879929697834668837":["b2b4","b2b4"],"7238784978024070913":["c5b4","c5b4"],"6600512175949942801":["c2c3","c2c3"],"3261523408786235287":["b4a5","b4a5"],"4230315701863478598":["d6d5"],"2861938524853600898":["d7d5"],"11735685704463928484":["e4d5"],"9584378767948418722":["d8d5"],"2911265455929472690":["d3e4"],"10454504206998793926":["d8d1"],"7350305895709186149":["e1d1"],"12132150523717821243":["g8f6"],"6750119798957118889":["f8c5"],"4242820514518567060":["h2h3"],"15137261875197985126":["g7g6"],"10289549487336384377":["e7g6"],"5220051548037671657":["h2h4"],"15335536355761197975":["c6d4"],"10566681265915912977":["g8f6"],"10800639567070590653":["a7a6"],"4609992647730214531":["c1b2","c1b2"],"14285285558929056513":["d2d4"],"219172444839267907":["e8g8"],"2669388567252954307":["c5d4"],"8796982489457198341":["f3d4"],"2126109872811153195":["d8c7"],"497424642828181063":["d2d4"],"14110369967925211909":["c5d4"],"11298582091248294595":["f3d4"],"18111504978769103085":["f8g7"],"1593678132437303580":["g1f3"],"14805879711965487137":["g8f6"],"10851205403948605879":["b1c3"],"7854314516627415918":["f8b4"],"11835820967722134348":["f3e5"],"1874267711705190010":["e8g8"],"7070236292076896595":["d7d5"],"16678136078984925045":["e4d5"],"13883381779998084467":["d8d5"],"4938055159587074233":["d2d4"],"9345620872749243899":["c5d4"],"5926926275479857887":["e8g8"],"6419081654265035039":["b7b5","b7b5","b7b5"],"4112141415769441314":["f1g2","f1g2","f1g2"],"10151502417522449499":["c8b7","c8b7","c8b7"],"4355307590555759552":["e1g1","e1g1","e1g1"],"4757017377534683344":["c7c5","c7c5","c7c5"],"12868335398863444457":["b1c3"],"5260937186738805552":["e7e5"],"5526166482892371277":["f2f4"],"12801092912325134532":["d8a5"],"7553956549649705291":["c1d2"],"4708603295574934095":["e7e5"],"2462118728632427888":["e1g1"],"6655837306656341600":["e8g8"],"15627157773965977987":["b7b6"],"12949986525335674392":["f1d3"],"16625664197270346859":["c8b7"],"6208899299542578160":["e1g1"],"2909055086512302304":["f8e7"],"14701213586411658557":["g8f6"],"10946851189728009387":["c1g5"],"5886574281156331363":["f6e4"],"7762266775241067161":["h2h4"],"17805685539382293479":["e4g5"],"11463155484354692750":["h4g5"],"11800612165204405722":["g7g6"],"15986574517404903812":["c4d5"],"15272026427842968985":["f6d5"],"5485201082702373061":["g2g3"],"6987864869295356157":["d5c3"],"1023252430469836185":["d1c2"],"13774858380942479869":["c7c5"],"6439005185894554240":["a2a3"],"10576633629493433536":["b4a5"],"8021624472593131008":["g1e2"],"8932436012539669502":["c7c5"],"13160493589783799455":["b1c3"],"5563194119229858886":["g7g6","b8c6"],"11853541053069227533":["g1f3"],"9215719704869803824":["d7d6"],"5070567605579636317":["d2d4"],"9469779144039756575":["e5d4"],"12814949031136312810":["e2e3"],"2088151309428602190":["c8f5"],"13891326727689953471":["b4c3"],"9365764857581422080":["f6g4"],"16419364122046350268":["e2e4"],"2871680160042417846":["d8h4"],"15698955747409524296":["d1e2"],"5754309707895385548":["b8c6"],"7757726455397884325":["g1f3"],"12739583754985685144":["f8d6"],"1098801528163212376":["g2g4"],"12426201021005885765":["h7h6"],"4505271552701544528":["f1g2"],"10049171129393700905":["c7c5"],"2437816364356718714":["f8g7"],"10477871544202304464":["b1c3"],"7710153787452201225":["e8g8"],"6942041208451592418":["e2e4"],"11839990883424521704":["d7d6"],"13968346407936094175":["c8f5"],"4810091252177921618":["f3h4"],"8319034479187830005":["f5e4"],"9642789693339474459":["e4c3"],"11466150237837569445":["d7d5"],"1906659748741505923":["g1f3"],"13911966724412113598":["g8h6"],"15035974313440697332":["d8b6"],"15431190861522067161":["g1e2"],"14015889903186727719":["c8b7"],"8265918214663234902":["c3d5"],"12872377343887251971":["a7a5"],"3799185679908574867":["g1f3"],"17284120243858700206":["d7d6"],"15449336462321761987":["a2a3"],"2160356243823210627":["b4c5"],"11345849003585357989":["g1f3"],"5048642350927751576":["g8f6"],"2161258937560655886":["e4e5"],"8973214933238861418":["f6d7"],"10666994214560458892":["g1f3","e2e4"],"16693532306468600026":["f8g7"],"6275601812180874096":["d2d4"],"7200324165078726730":["c1e3"],"1217110174676263876":["e7e5"],"8893150622681172161":["f1g2"],"14887477749745275064":["c8b7"],"9082276823513693987":["e1g1"],"35960158437254195":["f8e7"],"15742266229020208560":["g1e2"],"14903300077094936654":["b8c6"],"7638764407257291056":["d2d4"],"12625692484412830834":["c5d4"],"17305576755440557492":["f3d4"],"14771690643096122586":["f8b4"],"414049029354388728":["f1g2"],"12704333608717821057":["e8g8"],"13467506353102081386":["g1f3"],"7026421751329401943":["f8e8"],"17926779051825407213":["f8g7","f8g7"],"5220950870831689543":["d2d4","d2d4"],"10178597561225003525":["f7f5","f7f5"],"1814601091349835739":["g2g3","g2g3"],"3875480889394116579":["g8f6","g8f6"],"7937116744485500533":["f1g2","f1g2"],"15840970635094339084":["e8g8","e8g8"],"6807032120198199877":["d2d4"],"11187574861719361287":["g8f6"],"13883600369829090961":["g2g3"],"17026649746379558569":["e7e6"],"11114107121284701947":["f1g2"],"3444443340009735810":["f8b4"],"16612785005282348704":["b1d2"],"13826842037107287696":["e8g8"],"15581120753669719817":["f8g7"],"7532129034096118947":["d2d4"],"1442060832530394333":["d7d6"],"14429387517877313670":["c7c6"],"6504770782668305213":["c4d5"],"6084096316907366176":["e6d5"],"4871686644731556278":["c5d4"],"2055065316190935152":["d1d4"],"7003687585127642957":["g8f6"],"4689055884960126695":["f8c5","g8f6"],"17009984879765634148":["c1e3"],"11459163839963604970":["d8f6"],"14797167168476827975":["g1f3"],"1602988801825976442":["d7d5"],"10616347470832107100":["e2e3"],"4501793631326172920":["c7c5"],"15566767816039792005":["b2b3"],"6101854741237962472":["f7f6"],"11023442754644378007":["f1d3"],"14841194095651472356":["g7g6"],"5327227090898345081":["e7e6"],"4582956747822976043":["g2g4"],"11300920386738331958":["f5g6"],"942510525663483097":["f8c5"],"12745783186277945554":["d1a4"],"7081526722633441961":["c8d7"],"10400709639506846272":["e8g8"],"16634528397257425221":["a2a3"],"3262161216908414725":["d7d5"],"12119085731175301411":["c4d5"],"11963037868256630078":["f6d5"],"14629517878789701851":["d7d5","d7d5"],"1944688136365447025":["d4c6"],"6232515489653759556":["b7c6"],"12245558485149503974":["e2e3"],"350634523777882434":["f8e7"],"9224985916166284405":["b7b6"],"16964200804750791662":["f1g2"],"6817554546625923991":["c8b7"],"17153253552151225356":["b1c3"],"1556980601268790997":["g7g6"],"3027590569607711211":["g1f3"]}"#;
\ No newline at end of file
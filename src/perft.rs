@@ -0,0 +1,94 @@
+// Move generation counts for verifying the legal move generator against known
+// results, using the same clone-and-play path as the search.
+
+use shakmaty::{Move, Position};
+
+/// Number of leaf nodes of the legal move tree `depth` plies deep.
+pub fn perft<P: Position + Clone>(position: &P, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    let moves = position.legal_moves();
+    if depth == 1 {
+        return moves.len() as u64;
+    }
+    moves
+        .iter()
+        .map(|m| {
+            let mut new_position = position.clone();
+            new_position.play_unchecked(m);
+            perft(&new_position, depth - 1)
+        })
+        .sum()
+}
+
+/// `perft` of the position after each legal move, `depth` counting the move.
+pub fn divide<P: Position + Clone>(position: &P, depth: u32) -> Vec<(Move, u64)> {
+    position
+        .legal_moves()
+        .into_iter()
+        .map(|m| {
+            let mut new_position = position.clone();
+            new_position.play_unchecked(&m);
+            let nodes = perft(&new_position, depth.saturating_sub(1));
+            (m, nodes)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shakmaty::fen::Fen;
+    use shakmaty::{CastlingMode, Chess};
+
+    // Positions and node counts from https://www.chessprogramming.org/Perft_Results
+    const POSITIONS: &[(&str, &[u64])] = &[
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            &[20, 400, 8902, 197281],
+        ),
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            &[48, 2039, 97862, 4085603],
+        ),
+        (
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            &[14, 191, 2812, 43238, 674624],
+        ),
+        (
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+            &[6, 264, 9467, 422333],
+        ),
+        (
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+            &[44, 1486, 62379, 2103487],
+        ),
+        (
+            "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+            &[46, 2079, 89890, 3894594],
+        ),
+    ];
+
+    #[test]
+    fn test_perft() {
+        for (fen, counts) in POSITIONS {
+            let position: Chess = Fen::from_ascii(fen.as_bytes())
+                .unwrap()
+                .into_position(CastlingMode::Standard)
+                .unwrap();
+            assert_eq!(perft(&position, 0), 1);
+            for (depth, count) in counts.iter().enumerate() {
+                assert_eq!(perft(&position, depth as u32 + 1), *count, "{fen} {depth}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_divide() {
+        let position = Chess::new();
+        let moves = divide(&position, 3);
+        assert_eq!(moves.len(), 20);
+        assert_eq!(moves.iter().map(|(_, n)| n).sum::<u64>(), 8902);
+    }
+}
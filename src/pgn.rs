@@ -0,0 +1,551 @@
+// Buffered reader for the PGN export format.
+// Spec: https://www.saremba.de/chessgml/standards/pgn/pgn-complete.htm
+//
+// Only the main line is kept. Comments, variations, NAGs, move numbers and
+// move suffix annotations are skipped.
+
+use shakmaty::fen::Fen;
+use shakmaty::san::San;
+use shakmaty::{CastlingMode, Chess, Color, Outcome};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+#[derive(Debug)]
+pub enum PgnError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+}
+
+impl fmt::Display for PgnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PgnError::Io(err) => write!(f, "{err}"),
+            PgnError::Parse { line, message } => write!(f, "line {line}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for PgnError {}
+
+impl From<io::Error> for PgnError {
+    fn from(err: io::Error) -> PgnError {
+        PgnError::Io(err)
+    }
+}
+
+/// Tag pairs of a game in the order they appear.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Headers(pub Vec<(String, String)>);
+
+impl Headers {
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The `Result` tag, `None` for unfinished games.
+    pub fn result(&self) -> Option<Outcome> {
+        self.get("Result").and_then(parse_result)
+    }
+
+    pub fn white_elo(&self) -> Option<u16> {
+        self.get("WhiteElo")?.parse().ok()
+    }
+
+    pub fn black_elo(&self) -> Option<u16> {
+        self.get("BlackElo")?.parse().ok()
+    }
+
+    pub fn termination(&self) -> Option<&str> {
+        self.get("Termination")
+    }
+
+    /// The starting position given by the `FEN` tag, if any.
+    pub fn fen(&self) -> Option<Fen> {
+        self.get("FEN")?.parse().ok()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    pub headers: Headers,
+    pub moves: Vec<San>,
+    /// The game termination marker, `None` for `*`.
+    pub result: Option<Outcome>,
+}
+
+impl Game {
+    /// The position the moves are played from, `None` if the `FEN` tag is
+    /// not a valid position.
+    pub fn starting_position(&self) -> Option<Chess> {
+        match self.headers.get("FEN") {
+            Some(fen) => fen
+                .parse::<Fen>()
+                .ok()?
+                .into_position(CastlingMode::Standard)
+                .ok(),
+            None => Some(Chess::default()),
+        }
+    }
+}
+
+fn parse_result(value: &str) -> Option<Outcome> {
+    match value {
+        "1-0" => Some(Outcome::Decisive {
+            winner: Color::White,
+        }),
+        "0-1" => Some(Outcome::Decisive {
+            winner: Color::Black,
+        }),
+        "1/2-1/2" => Some(Outcome::Draw),
+        _ => None,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Tag(String, String),
+    Symbol(String),
+    Termination(Option<Outcome>),
+}
+
+pub struct PgnReader<R: BufRead> {
+    inner: R,
+    line: usize,
+    at_line_start: bool,
+    /// Set after an I/O error, which ends the iteration.
+    failed: bool,
+}
+
+impl PgnReader<BufReader<File>> {
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<PgnReader<BufReader<File>>> {
+        Ok(PgnReader::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+impl<R: BufRead> PgnReader<R> {
+    pub fn new(inner: R) -> PgnReader<R> {
+        PgnReader {
+            inner,
+            line: 1,
+            at_line_start: true,
+            failed: false,
+        }
+    }
+
+    /// Current line number, starting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    fn peek(&mut self) -> io::Result<Option<u8>> {
+        loop {
+            match self.inner.fill_buf() {
+                Ok(buf) => return Ok(buf.first().copied()),
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => (),
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    fn bump(&mut self) -> io::Result<Option<u8>> {
+        let c = self.peek()?;
+        if let Some(c) = c {
+            self.inner.consume(1);
+            self.at_line_start = c == b'\n';
+            if c == b'\n' {
+                self.line += 1;
+            }
+        }
+        Ok(c)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, PgnError> {
+        Err(PgnError::Parse {
+            line: self.line,
+            message: message.into(),
+        })
+    }
+
+    fn skip_line(&mut self) -> io::Result<()> {
+        while let Some(c) = self.bump()? {
+            if c == b'\n' {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn skip_comment(&mut self) -> Result<(), PgnError> {
+        let start = self.line;
+        loop {
+            match self.bump()? {
+                Some(b'}') => return Ok(()),
+                Some(_) => (),
+                None => {
+                    return Err(PgnError::Parse {
+                        line: start,
+                        message: "unterminated comment".to_string(),
+                    })
+                }
+            }
+        }
+    }
+
+    fn read_string(&mut self) -> Result<String, PgnError> {
+        let mut value = Vec::new();
+        loop {
+            match self.bump()? {
+                Some(b'"') => break,
+                Some(b'\\') => match self.bump()? {
+                    Some(c @ (b'"' | b'\\')) => value.push(c),
+                    Some(c) => value.extend([b'\\', c]),
+                    None => return self.error("unterminated string"),
+                },
+                Some(b'\n') | None => return self.error("unterminated string"),
+                Some(c) => value.push(c),
+            }
+        }
+        Ok(String::from_utf8_lossy(&value).into_owned())
+    }
+
+    fn skip_whitespace(&mut self) -> io::Result<()> {
+        while let Some(c) = self.peek()? {
+            if !c.is_ascii_whitespace() {
+                break;
+            }
+            self.bump()?;
+        }
+        Ok(())
+    }
+
+    fn read_tag(&mut self) -> Result<Token, PgnError> {
+        self.skip_whitespace()?;
+        let mut name = String::new();
+        while let Some(c) = self.peek()? {
+            if !(c.is_ascii_alphanumeric() || c == b'_') {
+                break;
+            }
+            name.push(c as char);
+            self.bump()?;
+        }
+        if name.is_empty() {
+            return self.error("missing tag name");
+        }
+
+        self.skip_whitespace()?;
+        if self.bump()? != Some(b'"') {
+            return self.error(format!("missing value for tag {name}"));
+        }
+        let value = self.read_string()?;
+
+        self.skip_whitespace()?;
+        if self.bump()? != Some(b']') {
+            return self.error(format!("unterminated tag {name}"));
+        }
+
+        Ok(Token::Tag(name, value))
+    }
+
+    fn read_symbol(&mut self) -> io::Result<String> {
+        let mut symbol = String::new();
+        while let Some(c) = self.peek()? {
+            if !(c.is_ascii_alphanumeric() || b"_+#=:-/".contains(&c)) {
+                break;
+            }
+            symbol.push(c as char);
+            self.bump()?;
+        }
+        Ok(symbol)
+    }
+
+    /// Returns the next tag, move or termination marker.
+    fn next_token(&mut self) -> Result<Option<Token>, PgnError> {
+        let mut variation_depth = 0;
+        let mut variation_line = 0;
+
+        loop {
+            let Some(c) = self.peek()? else {
+                if variation_depth > 0 {
+                    return Err(PgnError::Parse {
+                        line: variation_line,
+                        message: "unterminated variation".to_string(),
+                    });
+                }
+                return Ok(None);
+            };
+
+            match c {
+                b'%' if self.at_line_start => self.skip_line()?,
+                b';' => self.skip_line()?,
+                b'{' => {
+                    self.bump()?;
+                    self.skip_comment()?;
+                }
+                b'(' => {
+                    if variation_depth == 0 {
+                        variation_line = self.line;
+                    }
+                    variation_depth += 1;
+                    self.bump()?;
+                }
+                b')' => {
+                    if variation_depth == 0 {
+                        return self.error("unexpected ')'");
+                    }
+                    variation_depth -= 1;
+                    self.bump()?;
+                }
+                _ if variation_depth > 0 => {
+                    // Variations may contain anything the main line can.
+                    if c == b'"' {
+                        self.bump()?;
+                        self.read_string()?;
+                    } else {
+                        self.bump()?;
+                    }
+                }
+                b'[' => {
+                    self.bump()?;
+                    return self.read_tag().map(Some);
+                }
+                b'*' => {
+                    self.bump()?;
+                    return Ok(Some(Token::Termination(None)));
+                }
+                b'$' => {
+                    self.bump()?;
+                    let nag = self.read_symbol()?;
+                    if nag.is_empty() || !nag.bytes().all(|c| c.is_ascii_digit()) {
+                        return self.error("invalid NAG");
+                    }
+                }
+                b'.' | b'!' | b'?' => {
+                    // Move number periods and suffix annotations.
+                    self.bump()?;
+                }
+                _ if c.is_ascii_whitespace() => {
+                    self.bump()?;
+                }
+                _ if c.is_ascii_alphanumeric() => {
+                    let symbol = self.read_symbol()?;
+                    if symbol.bytes().all(|c| c.is_ascii_digit()) {
+                        // Move number.
+                        continue;
+                    }
+                    if let Some(outcome) = parse_result(&symbol) {
+                        return Ok(Some(Token::Termination(Some(outcome))));
+                    }
+                    return Ok(Some(Token::Symbol(symbol)));
+                }
+                b'\xef' if self.line == 1 => {
+                    // UTF-8 byte order mark.
+                    for expected in [b'\xef', b'\xbb', b'\xbf'] {
+                        if self.bump()? != Some(expected) {
+                            return self.error("invalid byte order mark");
+                        }
+                    }
+                }
+                _ => {
+                    self.bump()?;
+                    return self.error(format!("unexpected character {:?}", c as char));
+                }
+            }
+        }
+    }
+
+    fn read_game(&mut self) -> Result<Option<Game>, PgnError> {
+        let mut headers = Headers::default();
+        let mut moves = Vec::new();
+
+        loop {
+            let token = match self.next_token()? {
+                Some(token) => token,
+                None if headers.0.is_empty() && moves.is_empty() => return Ok(None),
+                None => return self.error("missing game termination marker"),
+            };
+
+            match token {
+                Token::Tag(name, value) => {
+                    if !moves.is_empty() {
+                        return self.error("tag after movetext");
+                    }
+                    headers.0.push((name, value));
+                }
+                Token::Symbol(symbol) => {
+                    // Some programs write castling with zeros.
+                    let symbol = match symbol.as_str() {
+                        "0-0" => "O-O".to_string(),
+                        "0-0-0" => "O-O-O".to_string(),
+                        "0-0+" | "0-0#" | "0-0-0+" | "0-0-0#" => symbol.replace('0', "O"),
+                        _ => symbol,
+                    };
+                    match symbol.parse::<San>() {
+                        Ok(san) => moves.push(san),
+                        Err(_) => return self.error(format!("invalid move {symbol}")),
+                    }
+                }
+                Token::Termination(result) => {
+                    return Ok(Some(Game {
+                        headers,
+                        moves,
+                        result,
+                    }))
+                }
+            }
+        }
+    }
+
+    /// Skips to the end of the current game after an error.
+    fn recover(&mut self) {
+        loop {
+            match self.next_token() {
+                Ok(Some(Token::Termination(_))) | Ok(None) => return,
+                Err(PgnError::Io(_)) => return,
+                _ => (),
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for PgnReader<R> {
+    type Item = Result<Game, PgnError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        match self.read_game() {
+            Ok(game) => game.map(Ok),
+            Err(err) => {
+                match err {
+                    PgnError::Parse { .. } => self.recover(),
+                    PgnError::Io(_) => self.failed = true,
+                }
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_all(pgn: &str) -> Vec<Result<Game, PgnError>> {
+        PgnReader::new(pgn.as_bytes()).collect()
+    }
+
+    fn san(moves: &[&str]) -> Vec<San> {
+        moves.iter().map(|m| m.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_headers_and_moves() {
+        let pgn = r#"[Event "Rated \"Blitz\" game"]
+[WhiteElo "1850"]
+[BlackElo "?"]
+[Result "1-0"]
+[Termination "Normal"]
+
+1. e4 e5 2. Nf3 Nc6 3. Bb5 a6 4. O-O 1-0
+"#;
+        let games = read_all(pgn);
+        assert_eq!(games.len(), 1);
+        let game = games[0].as_ref().unwrap();
+
+        assert_eq!(game.headers.get("Event"), Some(r#"Rated "Blitz" game"#));
+        assert_eq!(game.headers.white_elo(), Some(1850));
+        assert_eq!(game.headers.black_elo(), None);
+        assert_eq!(game.headers.termination(), Some("Normal"));
+        assert_eq!(game.headers.result(), game.result);
+        assert_eq!(
+            game.result,
+            Some(Outcome::Decisive {
+                winner: Color::White
+            })
+        );
+        assert_eq!(
+            game.moves,
+            san(&["e4", "e5", "Nf3", "Nc6", "Bb5", "a6", "O-O"])
+        );
+    }
+
+    #[test]
+    fn test_annotations() {
+        let pgn = "[Result \"*\"]\r\n\r\n\
+            1. e4 {best by test} e5!? 2. Nf3 $1 (2. f4 exf4 (2... d5) 3. Nf3) \
+            2... Nc6?? ; a comment\r\n\
+            3. Bc4! 0-0 *\r\n";
+        let games = read_all(pgn);
+        let game = games[0].as_ref().unwrap();
+
+        assert_eq!(game.result, None);
+        assert_eq!(game.moves, san(&["e4", "e5", "Nf3", "Nc6", "Bc4", "O-O"]));
+    }
+
+    #[test]
+    fn test_multiple_games() {
+        let pgn = "[Result \"0-1\"]\n\n1. f3 e5 2. g4 Qh4# 0-1\n\n\
+            [Result \"1/2-1/2\"]\n\n1. d4 d5 1/2-1/2\n\n\n";
+        let games = read_all(pgn);
+
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0].as_ref().unwrap().moves.len(), 4);
+        assert_eq!(games[1].as_ref().unwrap().result, Some(Outcome::Draw));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(read_all("").is_empty());
+        assert!(read_all("\n\n  \n").is_empty());
+    }
+
+    #[test]
+    fn test_errors() {
+        let pgn = "[Result \"1-0\"]\n\n1. e4 e5 2. Zz9 Nc6 1-0\n\n\
+            [Result \"1-0\"]\n\n1. e4 1-0\n\n\
+            [Result \"1-0\"]\n\n1. e4 {never closed\n";
+        let games = read_all(pgn);
+
+        assert_eq!(games.len(), 3);
+        match &games[0] {
+            Err(PgnError::Parse { line, .. }) => assert_eq!(*line, 3),
+            x => panic!("expected parse error, got {x:?}"),
+        }
+        assert_eq!(games[1].as_ref().unwrap().moves, san(&["e4"]));
+        match &games[2] {
+            Err(PgnError::Parse { line, .. }) => assert_eq!(*line, 11),
+            x => panic!("expected parse error, got {x:?}"),
+        }
+    }
+
+    #[test]
+    fn test_io_error() {
+        struct Failing;
+
+        impl io::Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("read failed"))
+            }
+        }
+
+        let games: Vec<_> = PgnReader::new(BufReader::new(Failing)).collect();
+        assert_eq!(games.len(), 1);
+        assert!(matches!(games[0], Err(PgnError::Io(_))));
+    }
+
+    #[test]
+    fn test_starting_position() {
+        let pgn = "[FEN \"4k3/8/8/8/8/8/8/4K2R w K - 0 1\"]\n[SetUp \"1\"]\n\n1. O-O *";
+        let games = read_all(pgn);
+        let game = games[0].as_ref().unwrap();
+
+        let position = game.starting_position().unwrap();
+        assert!(game.moves[0].to_move(&position).is_ok());
+    }
+}
@@ -1,19 +1,26 @@
+use crate::book::{self, Book, BookError, Learning, Selection};
 use crate::evaluation::evaluate;
 use crate::neural_eval;
-use crate::openings::OPENINGS;
+use crate::searchinfo::{Bound, Score, SearchInfo};
+use crate::skill::{Skill, Strength};
 use crate::transpositiontable::{EvaluationType, TranspositionTable};
-use rand::seq::SliceRandom;
+use shakmaty::variant::{Variant, VariantPosition};
 use shakmaty::zobrist::{Zobrist64, ZobristHash};
-use shakmaty::{uci::Uci, CastlingMode, Chess, Move, Position};
+use shakmaty::{uci::Uci, CastlingMode, Chess, Move, Outcome, Position};
 use shakmaty::{MoveList, Role, Square};
-use std::collections::HashMap;
-use std::str::FromStr;
+use std::collections::TryReserveError;
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::SystemTime;
 
 pub const POS_INF: i16 = 25_000;
 pub const NEG_INF: i16 = -25_000;
 
 const INITIAL_WINDOW_SIZE: i16 = 15;
+// Root moves are only reported once the search has run this long.
+const CURRMOVE_DELAY_MS: u64 = 1000;
 const R: u8 = 3;
 const LMR_CUTOFF: usize = 0;
 const LMR_DEPTH: u8 = 2;
@@ -27,130 +34,501 @@ const NULL_MOVE: Move = Move::Normal {
     promotion: None,
 };
 
+/// Positions the engine can search: standard chess and the variants of
+/// `VariantPosition`. The opening book and the neural network only know
+/// standard chess and are skipped for the other variants.
+pub trait SearchPosition: Position + Clone {
+    fn variant(&self) -> Variant;
+
+    /// The position if it is standard chess.
+    fn as_chess(&self) -> Option<&Chess>;
+
+    /// The position with the other side to move, `None` if that is not a
+    /// legal position.
+    fn null_move(self) -> Option<Self>;
+}
+
+impl SearchPosition for Chess {
+    fn variant(&self) -> Variant {
+        Variant::Chess
+    }
+
+    fn as_chess(&self) -> Option<&Chess> {
+        Some(self)
+    }
+
+    fn null_move(self) -> Option<Chess> {
+        self.swap_turn().ok()
+    }
+}
+
+impl SearchPosition for VariantPosition {
+    fn variant(&self) -> Variant {
+        VariantPosition::variant(self)
+    }
+
+    fn as_chess(&self) -> Option<&Chess> {
+        match self {
+            VariantPosition::Chess(chess) => Some(chess),
+            _ => None,
+        }
+    }
+
+    fn null_move(self) -> Option<VariantPosition> {
+        self.swap_turn().ok()
+    }
+}
+
+#[derive(Clone, Copy)]
 struct EngineOpts {
     pub use_book: bool,
+    pub book_selection: Selection,
+    /// Last full move number the book is used for.
+    pub book_depth: u32,
+    pub book_variety: u8,
     pub use_nn: bool,
+    pub print_info: bool,
+    pub max_nodes: u64,
+    /// Notation of castling moves in the UCI output.
+    pub castling_mode: CastlingMode,
+    /// Variant of the positions set up by the UCI protocol.
+    pub variant: Variant,
+    pub skill: Skill,
 }
 
 impl EngineOpts {
     pub fn new() -> EngineOpts {
         EngineOpts {
             use_book: true,
+            book_selection: Selection::Weighted,
+            book_depth: 20,
+            book_variety: 100,
             use_nn: false,
+            print_info: true,
+            max_nodes: u64::MAX,
+            castling_mode: CastlingMode::Standard,
+            variant: Variant::Chess,
+            skill: Skill::full_strength(),
         }
     }
 }
 
+// Deeper searches would overflow the depth counters.
+pub const MAX_DEPTH: u8 = 100;
+
+/// Limits of one search, each unlimited when `None`. A search without any
+/// limit runs to `MAX_DEPTH`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Milliseconds.
+    pub time: Option<u64>,
+    pub depth: Option<u8>,
+    pub nodes: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchResult {
+    pub best_move: Move,
+    /// `best_move` in the castling notation of the engine.
+    pub uci: Uci,
+    /// Centipawns from the point of view of the side to move, 0 for book moves.
+    pub score: i16,
+}
+
+/// Receives the progress of every search.
+pub type Reporter = Box<dyn FnMut(&SearchInfo) + Send>;
+
+/// Called with each root move and its number before it is searched.
+type OnRootMove<'a> = &'a mut dyn FnMut(&Move, usize);
+
+/// State shared by all the nodes of one search iteration.
+#[derive(Default)]
+struct Progress<'a> {
+    /// Deepest ply reached, including the quiescence search.
+    seldepth: u8,
+    on_root_move: Option<OnRootMove<'a>>,
+}
+
+/// A chess engine with its own hash table, opening book and options. Searches
+/// run on the calling thread and can be stopped from another one through
+/// `stop_signal`.
 pub struct Engine {
     tt: TranspositionTable,
-    book: HashMap<u64, Vec<String>>,
+    /// Used instead of `book::default_book` when set.
+    book: Option<Arc<Book>>,
+    learning: Option<Learning>,
+    /// Book moves played since the last new game, for learning.
+    played_book_moves: Vec<(Chess, Move)>,
+    strength: Strength,
+    reporter: Option<Reporter>,
+    /// The last report of a completed or failed iteration.
+    last_info: SearchInfo,
+    stop: Arc<AtomicBool>,
     opts: EngineOpts,
 }
 
 impl Engine {
     pub fn new() -> Engine {
-        let book = serde_json::from_str(OPENINGS).unwrap();
         Engine {
             tt: TranspositionTable::new(64),
-            book,
+            book: None,
+            learning: None,
+            played_book_moves: Vec::new(),
+            strength: Strength::default(),
+            reporter: None,
+            last_info: SearchInfo::default(),
+            stop: Arc::new(AtomicBool::new(false)),
             opts: EngineOpts::new(),
         }
     }
 
+    /// Clears the hash table and forgets the book moves played for learning.
     pub fn new_game(&mut self) {
         self.tt.clear();
+        self.played_book_moves.clear();
     }
 
-    pub fn set_hash(&mut self, value: usize) {
-        self.tt = TranspositionTable::new(value);
+    /// Updates the learning file with the book moves played in the game that
+    /// ended with `outcome`. Does nothing if the game was already recorded.
+    pub fn game_over(&mut self, outcome: Outcome) -> io::Result<()> {
+        let Some(learning) = &mut self.learning else {
+            return Ok(());
+        };
+        if self.played_book_moves.is_empty() {
+            return Ok(());
+        }
+        for (position, m) in self.played_book_moves.drain(..) {
+            learning.record(&position, &m, outcome);
+        }
+        learning.save()
     }
 
+    pub fn clear_hash(&mut self) {
+        self.tt.clear();
+    }
+
+    /// Replaces the hash table with an empty one of `value` megabytes, or
+    /// keeps the current table if the new one cannot be allocated.
+    pub fn set_hash(&mut self, value: usize) -> Result<(), TryReserveError> {
+        self.tt = TranspositionTable::try_new(value)?;
+        Ok(())
+    }
+
+    /// Plays standard chess positions from the opening book when it has moves.
     pub fn set_book(&mut self, value: bool) {
         self.opts.use_book = value;
     }
 
+    pub fn uses_book(&self) -> bool {
+        self.opts.use_book
+    }
+
+    pub fn set_book_selection(&mut self, value: Selection) {
+        self.opts.book_selection = value;
+    }
+
+    /// Uses the book up to and including full move `value`.
+    pub fn set_book_depth(&mut self, value: u32) {
+        self.opts.book_depth = value;
+    }
+
+    /// Percentage of the best book move's weight a move needs to be played,
+    /// subtracted from 100. See `book::choose`.
+    pub fn set_book_variety(&mut self, value: u8) {
+        self.opts.book_variety = value;
+    }
+
+    /// Uses the book at `path` instead of the default book, or the default
+    /// book again when `path` is `None`. See `Book::open` for the formats.
+    pub fn set_book_file(&mut self, path: Option<&Path>) -> Result<(), BookError> {
+        self.book = path.map(Book::open).transpose()?.map(Arc::new);
+        Ok(())
+    }
+
+    /// Records the results of book moves in the file at `path` and plays book
+    /// moves that lost less often, or stops learning when `path` is `None`.
+    pub fn set_learning_file(&mut self, path: Option<&Path>) -> Result<(), BookError> {
+        self.learning = path.map(Learning::open).transpose()?;
+        Ok(())
+    }
+
+    /// Corrects the evaluation of standard chess positions with the neural
+    /// network.
     pub fn set_nn(&mut self, value: bool) {
         self.opts.use_nn = value;
     }
 
-    fn iterative_deepening(
+    /// Enables or disables the `info` lines printed during search when there
+    /// is no reporter.
+    pub fn set_print_info(&mut self, value: bool) {
+        self.opts.print_info = value;
+    }
+
+    /// Limits the number of nodes searched per move.
+    pub fn set_max_nodes(&mut self, value: u64) {
+        self.opts.max_nodes = value;
+    }
+
+    /// Plays Chess960, where castling moves are written as the king capturing
+    /// its own rook.
+    pub fn set_chess960(&mut self, value: bool) {
+        self.opts.castling_mode = CastlingMode::from_chess960(value);
+    }
+
+    pub fn castling_mode(&self) -> CastlingMode {
+        self.opts.castling_mode
+    }
+
+    /// Sets the variant the GUI sets up positions in. The search itself plays
+    /// the variant of the position it is given.
+    pub fn set_variant(&mut self, value: Variant) {
+        self.opts.variant = value;
+    }
+
+    pub fn variant(&self) -> Variant {
+        self.opts.variant
+    }
+
+    /// Plays at `Skill Level` `value` unless the strength is limited by Elo.
+    pub fn set_skill_level(&mut self, value: u8) {
+        self.strength.level = value;
+        self.opts.skill = self.strength.skill();
+    }
+
+    /// Plays at the Elo set with `set_elo` instead of the skill level.
+    pub fn set_limit_strength(&mut self, value: bool) {
+        self.strength.limit = value;
+        self.opts.skill = self.strength.skill();
+    }
+
+    pub fn set_elo(&mut self, value: u32) {
+        self.strength.elo = value;
+        self.opts.skill = self.strength.skill();
+    }
+
+    /// Sends the progress of searches to `reporter` instead of printing
+    /// `info` lines, or restores the `info` lines if `None`.
+    pub fn set_reporter(&mut self, reporter: Option<Reporter>) {
+        self.reporter = reporter;
+    }
+
+    /// The last iteration of the last search, default if the move came from
+    /// the book.
+    pub fn last_info(&self) -> &SearchInfo {
+        &self.last_info
+    }
+
+    /// Setting the flag from another thread stops the running search, which
+    /// then returns the best move of the last finished iteration. Searches
+    /// return at once until the flag is cleared again.
+    pub fn stop_signal(&self) -> Arc<AtomicBool> {
+        self.stop.clone()
+    }
+
+    /// Whether a quiescence search agrees with the static evaluation of `position`.
+    pub fn is_quiet(&self, position: &Chess) -> bool {
+        let start_time = SystemTime::now();
+        let opts = EngineOpts {
+            use_nn: false,
+            max_nodes: u64::MAX,
+            ..self.opts
+        };
+        quiescence(
+            position,
+            NEG_INF,
+            POS_INF,
+            0,
+            &self.tt,
+            0,
+            u64::MAX,
+            &start_time,
+            &self.stop,
+            &opts,
+            &mut Progress::default(),
+        )
+        .is_some_and(|(evaluation, _)| evaluation == evaluate(position))
+    }
+
+    /// Evaluation of `position` at the leaves of the search, from the point of
+    /// view of the side to move.
+    pub fn static_eval<P: SearchPosition>(&self, position: &P) -> i16 {
+        static_eval(position, &self.opts)
+    }
+
+    /// Whether the opening book has moves for `position`, regardless of
+    /// `BookDepth`.
+    pub fn in_book(&self, position: &Chess) -> bool {
+        self.book
+            .clone()
+            .or_else(book::default_book)
+            .is_some_and(|book| !book.moves(position).is_empty())
+    }
+
+    fn report(&mut self, info: SearchInfo) {
+        report(&mut self.reporter, self.opts.print_info, &info);
+        self.last_info = info;
+    }
+
+    /// One iteration of iterative deepening from the root, reporting the root
+    /// moves as they are searched. Also returns the selective depth.
+    #[allow(clippy::too_many_arguments)]
+    fn search_root<P: SearchPosition>(
         &mut self,
-        position: &Chess,
+        position: &P,
+        alpha: i16,
+        beta: i16,
+        depth: u8,
+        nodes_searched: u64,
+        max_time: u64,
+        start_time: &SystemTime,
+        opts: &EngineOpts,
+    ) -> Option<(i16, Move, u64, u8)> {
+        let reporter = &mut self.reporter;
+        let castling_mode = opts.castling_mode;
+        let mut on_root_move = |m: &Move, number: usize| {
+            if start_time.elapsed().unwrap().as_millis() as u64 >= CURRMOVE_DELAY_MS {
+                let info = SearchInfo {
+                    depth,
+                    currmove: Some((m.to_uci(castling_mode), number)),
+                    ..SearchInfo::default()
+                };
+                report(reporter, opts.print_info, &info);
+            }
+        };
+        let mut progress = Progress {
+            seldepth: 0,
+            on_root_move: Some(&mut on_root_move),
+        };
+        let (evaluation, best_move, nodes_searched) = search(
+            position,
+            alpha,
+            beta,
+            depth,
+            0,
+            &mut self.tt,
+            nodes_searched,
+            max_time,
+            start_time,
+            &self.stop,
+            opts,
+            &mut progress,
+        )?;
+        Some((evaluation, best_move, nodes_searched, progress.seldepth))
+    }
+
+    fn iterative_deepening<P: SearchPosition>(
+        &mut self,
+        position: &P,
         max_time: u64,
         max_depth: u8,
     ) -> (Move, i16) {
         let start_time = SystemTime::now();
+        let castling_mode = self.opts.castling_mode;
+        let info = |depth, seldepth, nodes, score, bound, best_move: &Move| {
+            let time = start_time.elapsed().unwrap().as_millis() as u64;
+            let pv = if *best_move == NULL_MOVE {
+                Vec::new()
+            } else {
+                vec![best_move.to_uci(castling_mode)]
+            };
+            SearchInfo {
+                depth,
+                seldepth,
+                time,
+                nodes,
+                nps: nodes * 1000 / (time + 1),
+                score: Some(score),
+                bound,
+                pv,
+                currmove: None,
+            }
+        };
 
         // Initial guess for aspiration window
 
-        let (mut evaluation, mut best_move, mut nodes_searched) = search(
+        let opts = self.opts;
+        let Some((mut evaluation, mut best_move, mut nodes_searched, seldepth)) = self.search_root(
             position,
             NEG_INF,
             POS_INF,
             1,
             0,
-            &mut self.tt,
-            0,
             max_time,
             &start_time,
-            &self.opts,
-        )
-        .unwrap();
+            &opts,
+        ) else {
+            // Out of time or nodes before the first iteration finished.
+            return (position.legal_moves()[0].clone(), 0);
+        };
 
         let mut a_window = INITIAL_WINDOW_SIZE;
         let mut b_window = INITIAL_WINDOW_SIZE;
         let mut alpha = evaluation.saturating_sub(a_window);
         let mut beta = evaluation.saturating_add(b_window);
 
-        let nps = nodes_searched / (start_time.elapsed().unwrap().as_millis() as u64 + 1) * 1000;
-
-        println!("info nodes {0} nps {nps} depth 1", nodes_searched);
         if evaluation == POS_INF || evaluation == NEG_INF {
             let mate = find_mate(position, &mut self.tt, 1);
-            if mate.1 > 0 {
-                println!("info score mate 1");
-            } else {
-                println!("info score mate -1");
-            }
+            let moves = if mate.1 > 0 { 1 } else { -1 };
+            self.report(info(
+                1,
+                seldepth,
+                nodes_searched,
+                Score::Mate(moves),
+                Bound::Exact,
+                &mate.0,
+            ));
             return mate;
         }
-        println!("info score cp {}", evaluation);
+        self.report(info(
+            1,
+            seldepth,
+            nodes_searched,
+            Score::Cp(evaluation),
+            Bound::Exact,
+            &best_move,
+        ));
 
         let mut depth: u8 = 2;
 
         while depth <= max_depth {
-            let search = search(
+            let opts = self.opts;
+            let Some((new_evaluation, new_best_move, nodes, seldepth)) = self.search_root(
                 position,
                 alpha,
                 beta,
                 depth,
-                0,
-                &mut self.tt,
-                0,
+                nodes_searched,
                 max_time,
                 &start_time,
-                &self.opts,
-            );
-
-            let new_evaluation;
-            let new_best_move;
-
-            match search {
-                Some(s) => {
-                    new_evaluation = s.0;
-                    new_best_move = s.clone().1;
-                    nodes_searched += s.2;
-                }
-                None => break,
-            }
+                &opts,
+            ) else {
+                break;
+            };
+            nodes_searched = nodes;
 
             if new_evaluation <= alpha {
                 a_window *= 2;
                 alpha -= a_window;
+                self.report(info(
+                    depth,
+                    seldepth,
+                    nodes_searched,
+                    Score::Cp(new_evaluation),
+                    Bound::Upper,
+                    &best_move,
+                ));
                 continue;
             } else if new_evaluation >= beta {
                 b_window *= 2;
                 beta += b_window;
+                self.report(info(
+                    depth,
+                    seldepth,
+                    nodes_searched,
+                    Score::Cp(new_evaluation),
+                    Bound::Lower,
+                    &new_best_move,
+                ));
                 continue;
             } else {
                 a_window = INITIAL_WINDOW_SIZE;
@@ -159,23 +537,33 @@ impl Engine {
                 best_move = new_best_move;
             }
 
-            let nps =
-                nodes_searched / (start_time.elapsed().unwrap().as_millis() as u64 + 1) * 1000;
-
-            println!("info nodes {0} nps {nps} depth {depth}", nodes_searched);
             if evaluation == POS_INF || evaluation == NEG_INF {
                 let mate = find_mate(position, &mut self.tt, depth);
-                if mate.1 > 0 {
-                    println!("info score mate {depth}");
-                } else {
-                    println!("info score mate -{depth}");
-                }
-
                 if mate.0 != NULL_MOVE {
+                    let moves = if mate.1 > 0 {
+                        depth as i16
+                    } else {
+                        -(depth as i16)
+                    };
+                    self.report(info(
+                        depth,
+                        seldepth,
+                        nodes_searched,
+                        Score::Mate(moves),
+                        Bound::Exact,
+                        &mate.0,
+                    ));
                     return mate;
                 }
             }
-            println!("info score cp {}", evaluation);
+            self.report(info(
+                depth,
+                seldepth,
+                nodes_searched,
+                Score::Cp(evaluation),
+                Bound::Exact,
+                &best_move,
+            ));
 
             depth += 1;
         }
@@ -183,26 +571,141 @@ impl Engine {
         (best_move, evaluation)
     }
 
-    pub fn find_best_move(
+    fn book_move(&self, position: &Chess) -> Option<Move> {
+        if position.fullmoves().get() > self.opts.book_depth {
+            return None;
+        }
+
+        let book = self.book.clone().or_else(book::default_book)?;
+        let mut moves = book.moves(position);
+        if let Some(learning) = &self.learning {
+            learning.adjust(position, &mut moves);
+        }
+        book::choose(
+            moves,
+            self.opts.book_selection,
+            self.opts.book_variety,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Scores every root move and lets the skill level pick one of them.
+    fn weakened_move<P: SearchPosition>(
+        &mut self,
+        position: &P,
+        max_time: u64,
+        max_depth: u8,
+    ) -> (Move, i16) {
+        let skill = self.opts.skill;
+        let max_nodes = self.opts.max_nodes.min(skill.max_nodes());
+        let (moves, _) = self.score_root_moves(
+            position,
+            max_time,
+            max_depth.min(skill.max_depth()),
+            max_nodes,
+        );
+
+        skill
+            .choose(&moves, &mut rand::thread_rng())
+            .cloned()
+            .unwrap_or_else(|| (position.legal_moves()[0].clone(), 0))
+    }
+
+    /// Scores the root moves from the point of view of the side to move with
+    /// searches of increasing depth. All the searches share `max_time` and
+    /// `max_nodes`, and once either runs out the moves keep the scores of
+    /// their deepest finished search, so moves can be left out only if the
+    /// first iteration did not finish. Returns the scores and the number of
+    /// nodes searched.
+    fn score_root_moves<P: SearchPosition>(
         &mut self,
-        position: &Chess,
+        position: &P,
+        max_time: u64,
+        max_depth: u8,
+        max_nodes: u64,
+    ) -> (Vec<(Move, i16)>, u64) {
+        let start_time = SystemTime::now();
+        let mut nodes_searched = 0;
+        let mut moves: Vec<(Move, i16)> = Vec::new();
+
+        'deepening: for depth in 1..=max_depth.max(1) {
+            for (i, m) in position.legal_moves().into_iter().enumerate() {
+                let opts = EngineOpts {
+                    max_nodes: max_nodes.saturating_sub(nodes_searched),
+                    ..self.opts
+                };
+                let mut new_position = position.clone();
+                new_position.play_unchecked(&m);
+                let Some((score, _, nodes)) = search(
+                    &new_position,
+                    NEG_INF,
+                    POS_INF,
+                    depth - 1,
+                    1,
+                    &mut self.tt,
+                    0,
+                    max_time,
+                    &start_time,
+                    &self.stop,
+                    &opts,
+                    &mut Progress::default(),
+                ) else {
+                    break 'deepening;
+                };
+                nodes_searched += nodes;
+                match moves.get_mut(i) {
+                    Some(scored) => scored.1 = -score,
+                    None => moves.push((m, -score)),
+                }
+            }
+        }
+
+        (moves, nodes_searched)
+    }
+
+    /// The best move in `position` within `limits`, from the book if enabled.
+    /// `position` must have legal moves. `set_max_nodes` is ignored.
+    pub fn search<P: SearchPosition>(&mut self, position: &P, limits: &Limits) -> SearchResult {
+        let max_nodes = self.opts.max_nodes;
+        self.opts.max_nodes = limits.nodes.unwrap_or(u64::MAX);
+        let (best_move, uci, score) = self.find_best_move(
+            position,
+            limits.time.unwrap_or(u64::MAX),
+            limits.depth.unwrap_or(MAX_DEPTH).min(MAX_DEPTH),
+        );
+        self.opts.max_nodes = max_nodes;
+        SearchResult {
+            best_move,
+            uci,
+            score,
+        }
+    }
+
+    /// Like `search`, with a time limit in milliseconds and a depth limit.
+    pub fn find_best_move<P: SearchPosition>(
+        &mut self,
+        position: &P,
         max_time: u64,
         max_depth: u8,
     ) -> (Move, Uci, i16) {
         self.tt.clear();
-        let zobrist = position.zobrist_hash::<Zobrist64>(shakmaty::EnPassantMode::Legal);
-        if self.book.contains_key(&zobrist.0) && self.opts.use_book {
-            let moves = self.book.get(&zobrist.0).unwrap();
-            let move_string = moves.choose(&mut rand::thread_rng()).unwrap();
-            let uci = Uci::from_str(move_string).unwrap();
-            let chess_move = uci.to_move(position).unwrap();
-            return (chess_move, uci, 0);
+        self.last_info = SearchInfo::default();
+        if let Some(chess) = position.as_chess().filter(|_| self.opts.use_book) {
+            if let Some(chess_move) = self.book_move(chess) {
+                self.played_book_moves
+                    .push((chess.clone(), chess_move.clone()));
+                let uci = chess_move.to_uci(self.opts.castling_mode);
+                return (chess_move, uci, 0);
+            }
         }
-        let mut position = position.clone();
-        let (best_move, evaluation) = self.iterative_deepening(&mut position, max_time, max_depth);
+        let (best_move, evaluation) = if self.opts.skill.is_full_strength() {
+            self.iterative_deepening(position, max_time, max_depth)
+        } else {
+            self.weakened_move(position, max_time, max_depth)
+        };
         (
             best_move.clone(),
-            best_move.clone().to_uci(CastlingMode::Standard),
+            best_move.to_uci(self.opts.castling_mode),
             evaluation,
         )
     }
@@ -214,7 +717,31 @@ impl Default for Engine {
     }
 }
 
-fn find_mate(position: &Chess, tt: &mut TranspositionTable, max_depth: u8) -> (Move, i16) {
+fn report(reporter: &mut Option<Reporter>, print_info: bool, info: &SearchInfo) {
+    match reporter {
+        Some(reporter) => reporter(info),
+        None if print_info => println!("{}", info.to_uci()),
+        None => (),
+    }
+}
+
+/// The handcrafted evaluation, corrected by the neural network in quiet
+/// looking standard chess positions.
+fn static_eval<P: SearchPosition>(position: &P, opts: &EngineOpts) -> i16 {
+    let evaluation = evaluate(position);
+    if opts.use_nn && (-300..=300).contains(&evaluation) {
+        if let Some(chess) = position.as_chess() {
+            return evaluation + neural_eval::predict(chess);
+        }
+    }
+    evaluation
+}
+
+fn find_mate<P: SearchPosition>(
+    position: &P,
+    tt: &mut TranspositionTable,
+    max_depth: u8,
+) -> (Move, i16) {
     let mut depth = 0;
     loop {
         let search = mate_search(position, NEG_INF, POS_INF, depth, 0, tt);
@@ -225,8 +752,9 @@ fn find_mate(position: &Chess, tt: &mut TranspositionTable, max_depth: u8) -> (M
     }
 }
 
-fn mate_search(
-    position: &Chess,
+#[allow(clippy::only_used_in_recursion)]
+fn mate_search<P: SearchPosition>(
+    position: &P,
     mut alpha: i16,
     beta: i16,
     depth_left: u8,
@@ -288,17 +816,15 @@ fn sort_moves(scores: &mut [i16], moves: &mut MoveList) {
     for i in 1..scores.len() {
         let mut j = i;
         while j > 0 && scores[j] > scores[j - 1] {
-            unsafe {
-                scores.swap_unchecked(j, j - 1);
-                moves.swap_unchecked(j, j - 1);
-            }
+            scores.swap(j, j - 1);
+            moves.swap(j, j - 1);
             j -= 1;
         }
     }
 }
 
 #[inline]
-fn order_moves(position: &Chess, tt: &TranspositionTable, zobrist: u64) -> MoveList {
+fn order_moves<P: SearchPosition>(position: &P, tt: &TranspositionTable, zobrist: u64) -> MoveList {
     // MVV-LVA (most valuable capture, least valuable attacker)
     // Hash move
     let mut legal_moves = position.legal_moves();
@@ -341,7 +867,7 @@ fn order_moves(position: &Chess, tt: &TranspositionTable, zobrist: u64) -> MoveL
     legal_moves
 }
 
-fn is_promoting(position: &Chess) -> bool {
+fn is_promoting<P: SearchPosition>(position: &P) -> bool {
     let side_to_move = position.turn();
     let board = position.board();
     let pawns = board.pawns();
@@ -360,8 +886,9 @@ fn is_promoting(position: &Chess) -> bool {
     (side_pawns & promotion_mask).0 > 0
 }
 
-fn quiescence(
-    position: &Chess,
+#[allow(clippy::too_many_arguments, clippy::only_used_in_recursion)]
+fn quiescence<P: SearchPosition>(
+    position: &P,
     mut alpha: i16,
     beta: i16,
     depth_from_root: u8,
@@ -369,23 +896,25 @@ fn quiescence(
     mut nodes_searched: u64,
     max_time: u64,
     start_time: &SystemTime,
+    stop: &AtomicBool,
     opts: &EngineOpts,
+    progress: &mut Progress,
 ) -> Option<(i16, u64)> {
-    if start_time.elapsed().unwrap().as_millis() as u64 >= max_time {
+    if nodes_searched >= opts.max_nodes
+        || start_time.elapsed().unwrap().as_millis() as u64 >= max_time
+        || stop.load(Ordering::Relaxed)
+    {
         return None;
     }
 
     nodes_searched += 1;
+    progress.seldepth = progress.seldepth.max(depth_from_root);
 
     let zobrist = position
         .zobrist_hash::<Zobrist64>(shakmaty::EnPassantMode::Legal)
         .0;
 
-    let mut stand_pat = evaluate(position);
-
-    if opts.use_nn && stand_pat <= 300 || stand_pat >= -300 {
-        stand_pat += neural_eval::predict(position);
-    }
+    let stand_pat = static_eval(position, opts);
 
     if stand_pat >= beta {
         return Some((beta, nodes_searched));
@@ -413,7 +942,7 @@ fn quiescence(
         let mut new_position = position.clone();
         new_position.play_unchecked(&m);
 
-        if !m.is_capture() || !m.is_promotion() || !new_position.is_checkmate() {
+        if !m.is_capture() && !m.is_promotion() && !new_position.is_checkmate() {
             continue;
         }
 
@@ -421,12 +950,14 @@ fn quiescence(
             &new_position,
             -beta,
             -alpha,
-            depth_from_root,
+            depth_from_root + 1,
             tt,
             nodes_searched,
             max_time,
             start_time,
+            stop,
             opts,
+            progress,
         )?;
         nodes_searched = new_searched;
         let evaluation = -evaluation;
@@ -443,7 +974,7 @@ fn quiescence(
 }
 
 #[inline]
-fn is_passed_pawn(_position: &Chess, _m: &Move) -> bool {
+fn is_passed_pawn<P: SearchPosition>(_position: &P, _m: &Move) -> bool {
     // TODO
     // This function should get optimized away,
     // but I have left this here as a reminder to myself.
@@ -451,7 +982,7 @@ fn is_passed_pawn(_position: &Chess, _m: &Move) -> bool {
 }
 
 #[inline]
-fn calculate_extension(m: &Move, position: &Chess, depth_left: u8) -> u8 {
+fn calculate_extension<P: SearchPosition>(m: &Move, position: &P, depth_left: u8) -> u8 {
     if depth_left >= 3 {
         return 0;
     }
@@ -468,8 +999,9 @@ fn calculate_extension(m: &Move, position: &Chess, depth_left: u8) -> u8 {
     0
 }
 
-fn search(
-    position: &Chess,
+#[allow(clippy::too_many_arguments)]
+fn search<P: SearchPosition>(
+    position: &P,
     mut alpha: i16,
     beta: i16,
     depth_left: u8,
@@ -478,28 +1010,26 @@ fn search(
     mut nodes_searched: u64,
     max_time: u64,
     start_time: &SystemTime,
+    stop: &AtomicBool,
     opts: &EngineOpts,
+    progress: &mut Progress,
 ) -> Option<(i16, Move, u64)> {
-    if start_time.elapsed().unwrap().as_millis() as u64 >= max_time {
+    if nodes_searched >= opts.max_nodes
+        || start_time.elapsed().unwrap().as_millis() as u64 >= max_time
+        || stop.load(Ordering::Relaxed)
+    {
         return None;
     }
 
     nodes_searched += 1;
+    progress.seldepth = progress.seldepth.max(depth_from_root);
 
     let zobrist = position
         .zobrist_hash::<Zobrist64>(shakmaty::EnPassantMode::Legal)
         .0;
 
-    let table_lookup = tt.probe_table(&zobrist, depth_left, alpha, beta);
-    if table_lookup.is_some() {
-        let mut new_position = position.clone();
-        let chess_move = table_lookup.clone().unwrap().0;
-        new_position.play_unchecked(&chess_move);
-        return Some((
-            table_lookup.clone().unwrap().1,
-            table_lookup.unwrap().0,
-            nodes_searched,
-        ));
+    if let Some((chess_move, evaluation)) = tt.probe_table(&zobrist, depth_left, alpha, beta) {
+        return Some((evaluation, chess_move, nodes_searched));
     }
 
     if depth_left == 0 {
@@ -512,7 +1042,9 @@ fn search(
             nodes_searched,
             max_time,
             start_time,
+            stop,
             opts,
+            progress,
         )?;
         nodes_searched = new_seached;
 
@@ -539,7 +1071,9 @@ fn search(
                 nodes_searched,
                 max_time,
                 start_time,
+                stop,
                 opts,
+                progress,
             )?;
             nodes_searched = new_seached;
 
@@ -556,12 +1090,13 @@ fn search(
 
     let moves = order_moves(position, tt, zobrist);
 
-    let null_move_possible = !position.is_check();
-
-    if null_move_possible && depth_left >= 3 {
-        let new_position = position.clone();
-        let new_position = new_position.swap_turn().unwrap();
+    let null_move_position = if !position.is_check() && depth_left >= 3 {
+        position.clone().null_move()
+    } else {
+        None
+    };
 
+    if let Some(new_position) = null_move_position {
         let (evaluation, _, new_searched) = search(
             &new_position,
             -beta,
@@ -572,7 +1107,9 @@ fn search(
             nodes_searched,
             max_time,
             start_time,
+            stop,
             opts,
+            progress,
         )?;
 
         let evaluation = -evaluation;
@@ -592,6 +1129,12 @@ fn search(
     // main bit
 
     for (i, m) in moves.iter().enumerate() {
+        if depth_from_root == 0 {
+            if let Some(on_root_move) = &mut progress.on_root_move {
+                on_root_move(m, i + 1);
+            }
+        }
+
         // Make move.
         // Move is unmade automatically when `new_position` is dropped.
         let mut new_position = position.clone();
@@ -619,7 +1162,9 @@ fn search(
             nodes_searched,
             max_time,
             start_time,
+            stop,
             opts,
+            progress,
         )?;
         nodes_searched = new_searched;
         let evaluation = -evaluation;
@@ -643,14 +1188,53 @@ fn search(
     Some((alpha, best_move.clone(), nodes_searched))
 }
 
-extern crate test;
-
 #[cfg(test)]
 mod tests {
     use shakmaty::{fen::Fen, Chess};
 
     use super::*;
 
+    #[test]
+    fn test_first_iteration_limits() {
+        let mut engine = Engine::new();
+        engine.set_book(false);
+        engine.set_print_info(false);
+        let position = Chess::new();
+
+        // Without limits a search to depth 50 would not finish.
+        engine.set_max_nodes(10);
+        let (m, _, _) = engine.find_best_move(&position, u64::MAX, 50);
+        assert!(position.is_legal(&m));
+
+        engine.set_max_nodes(u64::MAX);
+        let (m, _, _) = engine.find_best_move(&position, 0, 50);
+        assert!(position.is_legal(&m));
+    }
+
+    #[test]
+    fn test_skill_node_limit() {
+        let mut engine = Engine::new();
+        engine.set_book(false);
+        engine.set_print_info(false);
+        engine.set_skill_level(0);
+        let skill = engine.opts.skill;
+        let position = Chess::new();
+
+        let (moves, nodes) = engine.score_root_moves(&position, u64::MAX, 50, skill.max_nodes());
+        assert!(nodes <= skill.max_nodes());
+        assert!(!moves.is_empty());
+        assert!(moves.iter().all(|(m, _)| position.is_legal(m)));
+
+        // Too few nodes to score any move.
+        let (moves, nodes) = engine.score_root_moves(&position, u64::MAX, 50, 1);
+        assert!(moves.is_empty());
+        assert_eq!(nodes, 0);
+
+        // Without the node limit a search to depth 50 would not finish.
+        let (m, _, _) = engine.find_best_move(&position, u64::MAX, 50);
+        assert!(position.is_legal(&m));
+    }
+
     #[test]
     fn test_alpha_beta() {
         // Create a test position
@@ -668,7 +1252,9 @@ mod tests {
             0,
             1000,
             &SystemTime::now(),
+            &AtomicBool::new(false),
             &EngineOpts::new(),
+            &mut Progress::default(),
         )
         .unwrap();
 
@@ -679,12 +1265,12 @@ mod tests {
     #[test]
     fn test_root_search() {
         // Create a test position
-        let mut position = Chess::new(); // You may want to set up a specific test position here
+        let position = Chess::new(); // You may want to set up a specific test position here
         let mut tt = TranspositionTable::new(64);
 
         // Call your alpha-beta function
         let (evaluation, _, _) = search(
-            &mut position,
+            &position,
             NEG_INF,
             POS_INF,
             3,
@@ -693,7 +1279,9 @@ mod tests {
             0,
             1000,
             &SystemTime::now(),
+            &AtomicBool::new(false),
             &EngineOpts::new(),
+            &mut Progress::default(),
         )
         .unwrap();
 
@@ -708,9 +1296,9 @@ mod tests {
 
         let fen: Fen = "6k1/2R5/8/8/8/3R4/2K5/8 w - - 0 1".parse().unwrap();
 
-        let mut position: Chess = fen.into_position(shakmaty::CastlingMode::Standard).unwrap();
+        let position: Chess = fen.into_position(shakmaty::CastlingMode::Standard).unwrap();
 
-        let (_, uci, _) = engine.find_best_move(&mut position, 1_000, 4);
+        let (_, uci, _) = engine.find_best_move(&position, 1_000, 4);
 
         assert_eq!(uci.to_string(), "d3d8".to_string());
 
@@ -718,9 +1306,9 @@ mod tests {
             .parse()
             .unwrap();
 
-        let mut position: Chess = fen.into_position(shakmaty::CastlingMode::Standard).unwrap();
+        let position: Chess = fen.into_position(shakmaty::CastlingMode::Standard).unwrap();
 
-        let (_, uci, _) = engine.find_best_move(&mut position, 1_000, 4);
+        let (_, uci, _) = engine.find_best_move(&position, 1_000, 4);
 
         assert_eq!(uci.to_string(), "e1f1".to_string());
 
@@ -728,9 +1316,9 @@ mod tests {
             .parse()
             .unwrap();
 
-        let mut position: Chess = fen.into_position(shakmaty::CastlingMode::Standard).unwrap();
+        let position: Chess = fen.into_position(shakmaty::CastlingMode::Standard).unwrap();
 
-        let (_, uci, _) = engine.find_best_move(&mut position, 1_000, 4);
+        let (_, uci, _) = engine.find_best_move(&position, 1_000, 4);
 
         assert_eq!(uci.to_string(), "e6e1".to_string());
 
@@ -738,13 +1326,45 @@ mod tests {
             .parse()
             .unwrap();
 
-        let mut position: Chess = fen.into_position(shakmaty::CastlingMode::Standard).unwrap();
+        let position: Chess = fen.into_position(shakmaty::CastlingMode::Standard).unwrap();
 
-        let (_, uci, _) = engine.find_best_move(&mut position, 1_000, 4);
+        let (_, uci, _) = engine.find_best_move(&position, 1_000, 4);
 
         assert_eq!(uci.to_string(), "e8e1".to_string());
     }
 
+    #[test]
+    fn test_quiescence() {
+        let tt = TranspositionTable::new(64);
+        let opts = EngineOpts::new();
+        let qsearch = |position: &Chess| {
+            quiescence(
+                position,
+                NEG_INF,
+                POS_INF,
+                0,
+                &tt,
+                0,
+                u64::MAX,
+                &SystemTime::now(),
+                &AtomicBool::new(false),
+                &opts,
+                &mut Progress::default(),
+            )
+            .unwrap()
+            .0
+        };
+
+        // Without the network a quiet position is scored by the static evaluation.
+        let position = Chess::new();
+        assert_eq!(qsearch(&position), evaluate(&position));
+
+        // The hanging queen is captured.
+        let fen: Fen = "7k/8/8/4q3/3Q4/8/8/K7 w - - 0 1".parse().unwrap();
+        let position: Chess = fen.into_position(CastlingMode::Standard).unwrap();
+        assert!(qsearch(&position) > evaluate(&position) + 500);
+    }
+
     #[test]
     fn test_captures() {
         let fen: Fen = "7k/8/8/4p3/3Q4/8/8/K7 w - - 0 1".parse().unwrap();
@@ -759,13 +1379,95 @@ mod tests {
 
         let fen: Fen = "7k/8/8/4q3/3Q4/8/8/K7 b - - 0 1".parse().unwrap();
 
-        let mut position: Chess = fen.into_position(shakmaty::CastlingMode::Standard).unwrap();
+        let position: Chess = fen.into_position(shakmaty::CastlingMode::Standard).unwrap();
 
-        let (_, uci, _) = engine.find_best_move(&mut position, 1_000, 40);
+        let (_, uci, _) = engine.find_best_move(&position, 1_000, 40);
 
         assert_eq!(uci.to_string(), "e5d4".to_string());
     }
 
+    #[test]
+    fn test_variants() {
+        let mut engine = Engine::new();
+
+        for variant in Variant::ALL {
+            let position = VariantPosition::new(variant);
+            let (m, _, _) = engine.find_best_move(&position, 1_000, 3);
+            assert!(position.is_legal(&m), "{variant}");
+        }
+
+        // Wins by the rules of the variant.
+        for (variant, fen, expected) in [
+            (
+                Variant::KingOfTheHill,
+                "k7/8/8/8/8/2K5/8/8 w - - 0 1",
+                "c3d4",
+            ),
+            (Variant::Atomic, "k7/1p6/8/8/8/8/8/1R5K w - - 0 1", "b1b7"),
+        ] {
+            let fen: Fen = fen.parse().unwrap();
+            let position =
+                VariantPosition::from_setup(variant, fen.into(), CastlingMode::Standard).unwrap();
+            let (_, uci, _) = engine.find_best_move(&position, 1_000, 3);
+            assert_eq!(uci.to_string(), expected, "{variant}");
+        }
+    }
+
+    #[test]
+    fn test_reporter() {
+        let infos = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut engine = Engine::new();
+        engine.set_book(false);
+        let reports = infos.clone();
+        engine.set_reporter(Some(Box::new(move |info: &SearchInfo| {
+            reports.lock().unwrap().push(info.clone());
+        })));
+
+        let (_, uci, evaluation) = engine.find_best_move(&Chess::new(), 10_000, 4);
+        let infos = infos.lock().unwrap();
+        let exact: Vec<&SearchInfo> = infos
+            .iter()
+            .filter(|info| info.currmove.is_none() && info.bound == Bound::Exact)
+            .collect();
+        let depths: Vec<u8> = exact.iter().map(|info| info.depth).collect();
+        assert_eq!(depths, [1, 2, 3, 4]);
+
+        let last = exact[3];
+        assert_eq!(engine.last_info(), last);
+        assert_eq!(last.score, Some(Score::Cp(evaluation)));
+        assert_eq!(last.pv, [uci]);
+        assert!(last.seldepth >= last.depth);
+        assert!(last.nodes > exact[2].nodes);
+    }
+
+    #[test]
+    fn test_limits() {
+        let mut engine = Engine::new();
+        engine.set_book(false);
+        engine.set_print_info(false);
+        let position = Chess::new();
+
+        let result = engine.search(
+            &position,
+            &Limits {
+                nodes: Some(5_000),
+                ..Limits::default()
+            },
+        );
+        assert!(position.is_legal(&result.best_move));
+        assert!(engine.last_info().nodes <= 5_000);
+
+        let result = engine.search(
+            &position,
+            &Limits {
+                depth: Some(2),
+                ..Limits::default()
+            },
+        );
+        assert_eq!(result.uci, result.best_move.to_uci(CastlingMode::Standard));
+        assert_eq!(engine.last_info().depth, 2);
+    }
+
     #[test]
     fn test_move_ordering() {
         let position = Chess::new();
@@ -0,0 +1,117 @@
+// Progress reports of a search. The engine sends one after every completed or
+// failed iteration of iterative deepening, and one before each root move once
+// the search has run for a while. The UCI front-end prints them as `info`
+// lines, other front-ends and library users can install their own reporter.
+
+use shakmaty::uci::Uci;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Score {
+    /// Centipawns from the point of view of the side to move.
+    Cp(i16),
+    /// Mate in this many moves, negative if the side to move gets mated.
+    Mate(i16),
+}
+
+/// Whether the score is exact or only a bound, after the score fell outside
+/// the aspiration window.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Bound {
+    #[default]
+    Exact,
+    Lower,
+    Upper,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchInfo {
+    pub depth: u8,
+    /// Deepest ply reached, including the quiescence search.
+    pub seldepth: u8,
+    /// Milliseconds since the search started.
+    pub time: u64,
+    pub nodes: u64,
+    pub nps: u64,
+    /// `None` in reports of the root move being searched.
+    pub score: Option<Score>,
+    pub bound: Bound,
+    pub pv: Vec<Uci>,
+    /// The root move being searched and its number, starting from 1.
+    pub currmove: Option<(Uci, usize)>,
+}
+
+impl SearchInfo {
+    /// The UCI `info` line.
+    pub fn to_uci(&self) -> String {
+        if let Some((m, number)) = &self.currmove {
+            return format!(
+                "info depth {} currmove {m} currmovenumber {number}",
+                self.depth
+            );
+        }
+        let mut line = format!(
+            "info depth {} seldepth {} time {} nodes {} nps {}",
+            self.depth, self.seldepth, self.time, self.nodes, self.nps
+        );
+        if let Some(score) = self.score {
+            line += &match score {
+                Score::Cp(cp) => format!(" score cp {cp}"),
+                Score::Mate(moves) => format!(" score mate {moves}"),
+            };
+            line += match self.bound {
+                Bound::Exact => "",
+                Bound::Lower => " lowerbound",
+                Bound::Upper => " upperbound",
+            };
+        }
+        if !self.pv.is_empty() {
+            let pv: Vec<String> = self.pv.iter().map(Uci::to_string).collect();
+            line += &format!(" pv {}", pv.join(" "));
+        }
+        line
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_uci() {
+        let info = SearchInfo {
+            depth: 5,
+            seldepth: 9,
+            time: 120,
+            nodes: 3000,
+            nps: 25000,
+            score: Some(Score::Cp(-20)),
+            bound: Bound::Lower,
+            pv: vec!["e2e4".parse().unwrap()],
+            currmove: None,
+        };
+        assert_eq!(
+            info.to_uci(),
+            "info depth 5 seldepth 9 time 120 nodes 3000 nps 25000 score cp -20 lowerbound pv e2e4"
+        );
+
+        let info = SearchInfo {
+            depth: 7,
+            score: Some(Score::Mate(-3)),
+            ..SearchInfo::default()
+        };
+        assert_eq!(
+            info.to_uci(),
+            "info depth 7 seldepth 0 time 0 nodes 0 nps 0 score mate -3"
+        );
+
+        let info = SearchInfo {
+            depth: 12,
+            currmove: Some(("g1f3".parse().unwrap(), 3)),
+            ..SearchInfo::default()
+        };
+        assert_eq!(
+            info.to_uci(),
+            "info depth 12 currmove g1f3 currmovenumber 3"
+        );
+    }
+}
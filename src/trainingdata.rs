@@ -0,0 +1,304 @@
+// Binary format for network training data.
+//
+// A file starts with an 8 byte header, `MKTD` followed by the format version
+// as a little endian u32, and is followed by fixed-size records of
+// `RECORD_SIZE` bytes. All integers are little endian.
+//
+//  offset  size  field
+//       0     8  white pieces
+//       8     8  black pieces
+//      16    48  pawns, knights, bishops, rooks, queens, kings
+//      64     2  search score in centipawns from white's point of view (i16)
+//      66     1  game result from white's point of view, 1, 0 or -1 (i8)
+//      67     1  flags, bit 0 is set when black is to move,
+//                bit 1 is set when the score is present
+
+use shakmaty::{Bitboard, Board, ByColor, ByRole, Chess, Color, Position, Role};
+use std::io::{self, ErrorKind, Read, Write};
+
+pub const MAGIC: &[u8; 4] = b"MKTD";
+pub const VERSION: u32 = 1;
+pub const HEADER_SIZE: usize = 8;
+pub const RECORD_SIZE: usize = 68;
+
+/// Number of network inputs produced by `Record::features`.
+pub const FEATURES: usize = 768;
+
+const FLAG_BLACK_TO_MOVE: u8 = 1;
+const FLAG_HAS_SCORE: u8 = 2;
+
+const COLORS: [Color; 2] = [Color::White, Color::Black];
+const ROLES: [Role; 6] = [
+    Role::Pawn,
+    Role::Knight,
+    Role::Bishop,
+    Role::Rook,
+    Role::Queen,
+    Role::King,
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub board: Board,
+    pub turn: Color,
+    /// Game result from white's point of view, 1, 0 or -1.
+    pub result: i8,
+    /// Search score in centipawns from white's point of view.
+    pub score: Option<i16>,
+}
+
+impl Record {
+    pub fn new(position: &Chess, result: i8, score: Option<i16>) -> Record {
+        Record {
+            board: position.board().clone(),
+            turn: position.turn(),
+            result,
+            score,
+        }
+    }
+
+    /// Indices of the non-zero network inputs, in the same order as the
+    /// serialization in `neural_eval.rs`.
+    pub fn features(&self) -> impl Iterator<Item = usize> + '_ {
+        COLORS.iter().enumerate().flat_map(move |(c, color)| {
+            ROLES.iter().enumerate().flat_map(move |(r, role)| {
+                let bb = self.board.by_color(*color) & self.board.by_role(*role);
+                bb.into_iter()
+                    .map(move |sq| (c * ROLES.len() + r) * 64 + sq as usize)
+            })
+        })
+    }
+
+    pub fn to_bytes(&self) -> [u8; RECORD_SIZE] {
+        let mut bytes = [0; RECORD_SIZE];
+
+        let bitboards = COLORS
+            .iter()
+            .map(|c| self.board.by_color(*c))
+            .chain(ROLES.iter().map(|r| self.board.by_role(*r)));
+        for (i, bb) in bitboards.enumerate() {
+            bytes[i * 8..i * 8 + 8].copy_from_slice(&bb.0.to_le_bytes());
+        }
+
+        bytes[64..66].copy_from_slice(&self.score.unwrap_or(0).to_le_bytes());
+        bytes[66] = self.result as u8;
+
+        let mut flags = 0;
+        if self.turn == Color::Black {
+            flags |= FLAG_BLACK_TO_MOVE;
+        }
+        if self.score.is_some() {
+            flags |= FLAG_HAS_SCORE;
+        }
+        bytes[67] = flags;
+
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8; RECORD_SIZE]) -> io::Result<Record> {
+        let bb = |i: usize| {
+            let mut word = [0; 8];
+            word.copy_from_slice(&bytes[i * 8..i * 8 + 8]);
+            Bitboard(u64::from_le_bytes(word))
+        };
+
+        let by_color = ByColor {
+            white: bb(0),
+            black: bb(1),
+        };
+        let by_role = ByRole {
+            pawn: bb(2),
+            knight: bb(3),
+            bishop: bb(4),
+            rook: bb(5),
+            queen: bb(6),
+            king: bb(7),
+        };
+
+        let mut occupied = Bitboard::EMPTY;
+        for role in ROLES {
+            let bb = *by_role.get(role);
+            if !occupied.is_disjoint(bb) {
+                return Err(invalid_data("overlapping piece bitboards"));
+            }
+            occupied |= bb;
+        }
+        if !by_color.white.is_disjoint(by_color.black)
+            || occupied != by_color.white | by_color.black
+        {
+            return Err(invalid_data("inconsistent color bitboards"));
+        }
+
+        let result = bytes[66] as i8;
+        if !(-1..=1).contains(&result) {
+            return Err(invalid_data("invalid game result"));
+        }
+
+        let flags = bytes[67];
+        if flags & !(FLAG_BLACK_TO_MOVE | FLAG_HAS_SCORE) != 0 {
+            return Err(invalid_data("unknown flags"));
+        }
+
+        let turn = if flags & FLAG_BLACK_TO_MOVE != 0 {
+            Color::Black
+        } else {
+            Color::White
+        };
+        let score = if flags & FLAG_HAS_SCORE != 0 {
+            Some(i16::from_le_bytes([bytes[64], bytes[65]]))
+        } else {
+            None
+        };
+
+        Ok(Record {
+            board: Board::from_bitboards(by_role, by_color),
+            turn,
+            result,
+            score,
+        })
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message)
+}
+
+pub struct RecordWriter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> RecordWriter<W> {
+    /// Writes the file header.
+    pub fn new(mut inner: W) -> io::Result<RecordWriter<W>> {
+        inner.write_all(MAGIC)?;
+        inner.write_all(&VERSION.to_le_bytes())?;
+        Ok(RecordWriter { inner })
+    }
+
+    pub fn write(&mut self, record: &Record) -> io::Result<()> {
+        self.inner.write_all(&record.to_bytes())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+pub struct RecordReader<R: Read> {
+    inner: R,
+}
+
+impl<R: Read> RecordReader<R> {
+    /// Reads and checks the file header.
+    pub fn new(mut inner: R) -> io::Result<RecordReader<R>> {
+        let mut header = [0; HEADER_SIZE];
+        inner.read_exact(&mut header)?;
+
+        if &header[..4] != MAGIC {
+            return Err(invalid_data("not a training data file"));
+        }
+        let version = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+        if version != VERSION {
+            return Err(invalid_data("unsupported training data version"));
+        }
+
+        Ok(RecordReader { inner })
+    }
+}
+
+impl<R: Read> Iterator for RecordReader<R> {
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut bytes = [0; RECORD_SIZE];
+        let mut filled = 0;
+
+        while filled < RECORD_SIZE {
+            match self.inner.read(&mut bytes[filled..]) {
+                Ok(0) if filled == 0 => return None,
+                Ok(0) => return Some(Err(invalid_data("truncated record"))),
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => (),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
+        Some(Record::from_bytes(&bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use shakmaty::fen::Fen;
+    use shakmaty::CastlingMode;
+
+    use super::*;
+
+    fn position(fen: &str) -> Chess {
+        let fen: Fen = fen.parse().unwrap();
+        fen.into_position(CastlingMode::Standard).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let records = [
+            Record::new(&Chess::new(), 0, None),
+            Record::new(
+                &position("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq - 0 10"),
+                -1,
+                Some(-123),
+            ),
+            Record::new(
+                &position("8/8/8/8/5kp1/P7/8/1K1N4 w - - 0 1"),
+                1,
+                Some(i16::MAX),
+            ),
+        ];
+
+        let mut writer = RecordWriter::new(Vec::new()).unwrap();
+        for record in &records {
+            writer.write(record).unwrap();
+        }
+        let bytes = writer.into_inner();
+
+        assert_eq!(bytes.len(), HEADER_SIZE + records.len() * RECORD_SIZE);
+
+        let reader = RecordReader::new(bytes.as_slice()).unwrap();
+        let read: Vec<Record> = reader.map(|r| r.unwrap()).collect();
+
+        assert_eq!(read, records);
+    }
+
+    #[test]
+    fn test_features() {
+        let record = Record::new(&Chess::new(), 0, None);
+        let features: Vec<usize> = record.features().collect();
+
+        assert_eq!(features.len(), 32);
+        // White pawn on a2 and black king on e8.
+        assert!(features.contains(&8));
+        assert!(features.contains(&(11 * 64 + 60)));
+        assert!(features.iter().all(|f| *f < FEATURES));
+    }
+
+    #[test]
+    fn test_invalid_data() {
+        assert!(RecordReader::new(&b"not data"[..]).is_err());
+
+        let mut bytes = Record::new(&Chess::new(), 0, None).to_bytes();
+        bytes[0] = 0xff;
+        bytes[8] = 0xff;
+        assert!(Record::from_bytes(&bytes).is_err());
+
+        let mut writer = RecordWriter::new(Vec::new()).unwrap();
+        writer.write(&Record::new(&Chess::new(), 0, None)).unwrap();
+        let mut bytes = writer.into_inner();
+        bytes.pop();
+        let mut reader = RecordReader::new(bytes.as_slice()).unwrap();
+        assert!(reader.next().unwrap().is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use shakmaty::{Move, Role, Square};
+use std::collections::TryReserveError;
 use std::mem::size_of;
 
 const NULL_MOVE: Move = Move::Normal {
@@ -44,19 +45,27 @@ pub struct TranspositionTable {
 
 impl TranspositionTable {
     pub fn new(desired_size_in_mb: usize) -> TranspositionTable {
+        TranspositionTable::try_new(desired_size_in_mb).expect("cannot allocate hash table")
+    }
+
+    /// Fails instead of aborting when the table cannot be allocated.
+    pub fn try_new(desired_size_in_mb: usize) -> Result<TranspositionTable, TryReserveError> {
         let tt_entry_size_in_bytes: usize = size_of::<Transposition>();
-        let desired_size_in_bytes = desired_size_in_mb * 1024 * 1024;
+        let desired_size_in_bytes = desired_size_in_mb.saturating_mul(1024 * 1024);
         let num_entries: usize = (desired_size_in_bytes) / tt_entry_size_in_bytes;
 
-        TranspositionTable {
-            transpositions: vec![Transposition::new(); num_entries],
+        let mut transpositions = Vec::new();
+        transpositions.try_reserve_exact(num_entries)?;
+        transpositions.resize(num_entries, Transposition::new());
+
+        Ok(TranspositionTable {
+            transpositions,
             count: num_entries as u64,
-        }
+        })
     }
 
     pub fn clear(&mut self) {
-        let len = self.transpositions.len();
-        self.transpositions = vec![Transposition::new(); len];
+        self.transpositions.fill(Transposition::new());
     }
 
     #[inline]
@@ -0,0 +1,387 @@
+// Parser for the commands a GUI sends to the engine, one command per line.
+//
+// The grammar follows the UCI spec: a line is a command followed by its
+// arguments. Unknown tokens before the command are skipped, as are unknown
+// tokens between the arguments of `go`, so that a newer GUI can talk to the
+// engine. Anything else that does not fit the grammar is a `UciError`, which
+// the protocol reports as an `info string`. Parsing never panics.
+
+use shakmaty::fen::Fen;
+use shakmaty::uci::Uci;
+use std::fmt;
+use std::str::SplitWhitespace;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UciCommand {
+    Uci,
+    /// `debug on|off`, accepted and ignored.
+    Debug,
+    IsReady,
+    SetOption {
+        name: String,
+        value: Option<String>,
+    },
+    /// `register`, accepted and ignored as the engine is free.
+    Register,
+    UciNewGame,
+    Position {
+        /// `None` for `startpos`.
+        fen: Option<Fen>,
+        moves: Vec<Uci>,
+    },
+    Go(GoParams),
+    /// `perft <depth>` or `go perft <depth>`, counts the leaf nodes of the
+    /// move tree.
+    Perft(u32),
+    /// `divide <depth>`, `perft` for each legal move.
+    Divide(u32),
+    /// `d` or `display`, prints the current position.
+    Display,
+    Stop,
+    PonderHit,
+    Quit,
+}
+
+/// Arguments of `go`. `searchmoves`, `ponder`, `movestogo` and `mate` are
+/// parsed but not used by the search.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GoParams {
+    pub wtime: Option<u64>,
+    pub btime: Option<u64>,
+    pub winc: Option<u64>,
+    pub binc: Option<u64>,
+    pub depth: Option<u64>,
+    pub nodes: Option<u64>,
+    pub movetime: Option<u64>,
+    pub infinite: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UciError {
+    Empty,
+    UnknownCommand(String),
+    MissingArgument(&'static str),
+    InvalidNumber(&'static str, String),
+    InvalidFen(String),
+    InvalidMove(String),
+    UnexpectedToken(String),
+    IllegalPosition(String),
+    IllegalMove(String),
+    UnknownOption(String),
+    /// An option value that was rejected, with the reason.
+    InvalidOption(String),
+}
+
+impl fmt::Display for UciError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UciError::Empty => write!(f, "empty command"),
+            UciError::UnknownCommand(command) => write!(f, "unknown command {command}"),
+            UciError::MissingArgument(usage) => write!(f, "usage: {usage}"),
+            UciError::InvalidNumber(name, value) => {
+                write!(f, "invalid value {value} for {name}")
+            }
+            UciError::InvalidFen(fen) => write!(f, "invalid fen {fen}"),
+            UciError::InvalidMove(m) => write!(f, "invalid move {m}"),
+            UciError::UnexpectedToken(token) => write!(f, "unexpected {token}"),
+            UciError::IllegalPosition(fen) => write!(f, "illegal position {fen}"),
+            UciError::IllegalMove(m) => write!(f, "illegal move {m}"),
+            UciError::UnknownOption(name) => write!(f, "unknown option {name}"),
+            UciError::InvalidOption(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+const SETOPTION_USAGE: &str = "setoption name <id> [value <x>]";
+const POSITION_USAGE: &str = "position [fen <fenstring> | startpos] [moves <move1> ... <movei>]";
+const PERFT_USAGE: &str = "perft <depth>";
+const DIVIDE_USAGE: &str = "divide <depth>";
+
+// Every token that starts a command.
+const COMMANDS: &[&str] = &[
+    "uci",
+    "debug",
+    "isready",
+    "setoption",
+    "register",
+    "ucinewgame",
+    "position",
+    "go",
+    "perft",
+    "divide",
+    "d",
+    "display",
+    "stop",
+    "ponderhit",
+    "quit",
+];
+
+impl UciCommand {
+    pub fn parse(line: &str) -> Result<UciCommand, UciError> {
+        let mut tokens = line.split_whitespace();
+        let Some(command) = tokens.by_ref().find(|t| COMMANDS.contains(t)) else {
+            return Err(match line.split_whitespace().next() {
+                Some(token) => UciError::UnknownCommand(token.to_string()),
+                None => UciError::Empty,
+            });
+        };
+
+        match command {
+            "uci" => Ok(UciCommand::Uci),
+            "debug" => Ok(UciCommand::Debug),
+            "isready" => Ok(UciCommand::IsReady),
+            "setoption" => parse_setoption(tokens),
+            "register" => Ok(UciCommand::Register),
+            "ucinewgame" => Ok(UciCommand::UciNewGame),
+            "position" => parse_position(tokens),
+            "go" if tokens.clone().next() == Some("perft") => {
+                parse_depth(tokens.skip(1), PERFT_USAGE).map(UciCommand::Perft)
+            }
+            "go" => parse_go(tokens).map(UciCommand::Go),
+            "perft" => parse_depth(tokens, PERFT_USAGE).map(UciCommand::Perft),
+            "divide" => parse_depth(tokens, DIVIDE_USAGE).map(UciCommand::Divide),
+            "d" | "display" => Ok(UciCommand::Display),
+            "stop" => Ok(UciCommand::Stop),
+            "ponderhit" => Ok(UciCommand::PonderHit),
+            _ => Ok(UciCommand::Quit),
+        }
+    }
+}
+
+fn parse_setoption(tokens: SplitWhitespace) -> Result<UciCommand, UciError> {
+    // Names and values can contain spaces, the name ends at `value` and the
+    // value at the end of the line.
+    let words: Vec<&str> = tokens.collect();
+    if words.first() != Some(&"name") {
+        return Err(UciError::MissingArgument(SETOPTION_USAGE));
+    }
+    let end = words.iter().position(|w| *w == "value");
+    let name = words[1..end.unwrap_or(words.len())].join(" ");
+    if name.is_empty() {
+        return Err(UciError::MissingArgument(SETOPTION_USAGE));
+    }
+    let value = end.map(|end| words[end + 1..].join(" "));
+    Ok(UciCommand::SetOption { name, value })
+}
+
+fn parse_position(tokens: SplitWhitespace) -> Result<UciCommand, UciError> {
+    let words: Vec<&str> = tokens.collect();
+    let (position, moves) = match words.iter().position(|w| *w == "moves") {
+        Some(i) => (&words[..i], &words[i + 1..]),
+        None => (&words[..], &[][..]),
+    };
+
+    let fen = match position {
+        ["startpos"] => None,
+        ["startpos", token, ..] => return Err(UciError::UnexpectedToken(token.to_string())),
+        ["fen", fields @ ..] => {
+            // The clocks are optional, and a three-check FEN has the remaining
+            // checks as an extra field after the en passant square.
+            let fen = fields.join(" ");
+            match fen.parse() {
+                Ok(fen) => Some(fen),
+                Err(_) => return Err(UciError::InvalidFen(fen)),
+            }
+        }
+        _ => return Err(UciError::MissingArgument(POSITION_USAGE)),
+    };
+
+    let moves = moves
+        .iter()
+        .map(|m| m.parse().map_err(|_| UciError::InvalidMove(m.to_string())))
+        .collect::<Result<_, _>>()?;
+
+    Ok(UciCommand::Position { fen, moves })
+}
+
+fn parse_go(mut tokens: SplitWhitespace) -> Result<GoParams, UciError> {
+    let mut params = GoParams::default();
+
+    while let Some(token) = tokens.next() {
+        let mut value = |name: &'static str| -> Result<(&'static str, &str), UciError> {
+            let value = tokens.next().ok_or(UciError::MissingArgument(name))?;
+            Ok((name, value))
+        };
+        match token {
+            "wtime" => params.wtime = Some(parse_time(value("go wtime <x>")?)?),
+            "btime" => params.btime = Some(parse_time(value("go btime <x>")?)?),
+            "winc" => params.winc = Some(parse_time(value("go winc <x>")?)?),
+            "binc" => params.binc = Some(parse_time(value("go binc <x>")?)?),
+            "depth" => params.depth = Some(parse_number(value("go depth <x>")?)?),
+            "nodes" => params.nodes = Some(parse_number(value("go nodes <x>")?)?),
+            "movetime" => params.movetime = Some(parse_time(value("go movetime <x>")?)?),
+            "movestogo" => {
+                parse_number(value("go movestogo <x>")?)?;
+            }
+            "mate" => {
+                parse_number(value("go mate <x>")?)?;
+            }
+            "infinite" => params.infinite = true,
+            // The moves of `searchmoves` are skipped like unknown tokens.
+            _ => (),
+        }
+    }
+
+    Ok(params)
+}
+
+fn parse_number((name, value): (&'static str, &str)) -> Result<u64, UciError> {
+    value
+        .parse()
+        .map_err(|_| UciError::InvalidNumber(name, value.to_string()))
+}
+
+/// GUIs send negative clocks once the engine has run out of time, which count
+/// as no time left so that the engine still answers with a move.
+fn parse_time((name, value): (&'static str, &str)) -> Result<u64, UciError> {
+    value
+        .parse::<i64>()
+        .map(|time| time.max(0) as u64)
+        .map_err(|_| UciError::InvalidNumber(name, value.to_string()))
+}
+
+fn parse_depth<'a>(
+    mut tokens: impl Iterator<Item = &'a str>,
+    usage: &'static str,
+) -> Result<u32, UciError> {
+    let depth = tokens.next().ok_or(UciError::MissingArgument(usage))?;
+    depth
+        .parse()
+        .map_err(|_| UciError::InvalidNumber(usage, depth.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn go(line: &str) -> GoParams {
+        match UciCommand::parse(line) {
+            Ok(UciCommand::Go(params)) => params,
+            other => panic!("{line}: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(UciCommand::parse("uci"), Ok(UciCommand::Uci));
+        assert_eq!(UciCommand::parse("  isready \t"), Ok(UciCommand::IsReady));
+        assert_eq!(UciCommand::parse("joho debug on"), Ok(UciCommand::Debug));
+        assert_eq!(UciCommand::parse("d"), Ok(UciCommand::Display));
+        assert_eq!(UciCommand::parse(""), Err(UciError::Empty));
+        assert_eq!(
+            UciCommand::parse("hello world"),
+            Err(UciError::UnknownCommand("hello".into()))
+        );
+
+        assert_eq!(
+            UciCommand::parse("setoption name Clear Hash"),
+            Ok(UciCommand::SetOption {
+                name: "Clear Hash".into(),
+                value: None
+            })
+        );
+        assert_eq!(
+            UciCommand::parse("setoption name BookFile value my book.bin"),
+            Ok(UciCommand::SetOption {
+                name: "BookFile".into(),
+                value: Some("my book.bin".into())
+            })
+        );
+        assert!(UciCommand::parse("setoption value 1").is_err());
+        assert!(UciCommand::parse("setoption name value 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_position() {
+        let moves = |ms: &[&str]| -> Vec<Uci> { ms.iter().map(|m| m.parse().unwrap()).collect() };
+
+        assert_eq!(
+            UciCommand::parse("position startpos moves e2e4 e7e5"),
+            Ok(UciCommand::Position {
+                fen: None,
+                moves: moves(&["e2e4", "e7e5"])
+            })
+        );
+
+        let fen = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1";
+        assert_eq!(
+            UciCommand::parse(&format!("position fen {fen} moves e1g1")),
+            Ok(UciCommand::Position {
+                fen: Some(fen.parse().unwrap()),
+                moves: moves(&["e1g1"])
+            })
+        );
+
+        // Without the clocks.
+        assert_eq!(
+            UciCommand::parse("position fen 8/8/8/8/8/6k1/6p1/6K1 w - -"),
+            Ok(UciCommand::Position {
+                fen: Some("8/8/8/8/8/6k1/6p1/6K1 w - -".parse().unwrap()),
+                moves: Vec::new()
+            })
+        );
+
+        assert!(matches!(
+            UciCommand::parse("position fen 8/8/8 w - - moves e2e4"),
+            Err(UciError::InvalidFen(_))
+        ));
+        assert!(matches!(
+            UciCommand::parse("position fen"),
+            Err(UciError::InvalidFen(_))
+        ));
+        assert!(matches!(
+            UciCommand::parse("position startpos moves e2e4 castle"),
+            Err(UciError::InvalidMove(_))
+        ));
+        assert!(matches!(
+            UciCommand::parse("position startpos e2e4"),
+            Err(UciError::UnexpectedToken(_))
+        ));
+        assert!(matches!(
+            UciCommand::parse("position"),
+            Err(UciError::MissingArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_go() {
+        assert_eq!(go("go"), GoParams::default());
+        assert_eq!(
+            go("go wtime 300000 btime 290000 winc 2000 binc 2000 movestogo 40"),
+            GoParams {
+                wtime: Some(300000),
+                btime: Some(290000),
+                winc: Some(2000),
+                binc: Some(2000),
+                ..GoParams::default()
+            }
+        );
+        assert_eq!(
+            go("go searchmoves e2e4 d2d4 depth 7 movetime 5000 future 1"),
+            GoParams {
+                depth: Some(7),
+                movetime: Some(5000),
+                ..GoParams::default()
+            }
+        );
+        assert!(go("go infinite").infinite);
+
+        assert_eq!(UciCommand::parse("go perft 5"), Ok(UciCommand::Perft(5)));
+        assert_eq!(UciCommand::parse("perft 4"), Ok(UciCommand::Perft(4)));
+        assert_eq!(UciCommand::parse("divide 3"), Ok(UciCommand::Divide(3)));
+        assert!(matches!(
+            UciCommand::parse("go perft"),
+            Err(UciError::MissingArgument(_))
+        ));
+        assert!(matches!(
+            UciCommand::parse("divide x"),
+            Err(UciError::InvalidNumber(..))
+        ));
+
+        assert!(matches!(
+            UciCommand::parse("go depth"),
+            Err(UciError::MissingArgument(_))
+        ));
+        assert_eq!(go("go wtime -5").wtime, Some(0));
+    }
+}
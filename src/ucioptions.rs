@@ -0,0 +1,405 @@
+// UCI options supported by the engine. The table drives both the `uci`
+// option listing and `setoption`, and the defaults are applied to the engine
+// when the protocol starts so the advertised defaults are always in effect.
+
+use crate::search::Engine;
+use crate::skill;
+use shakmaty::variant::Variant;
+use std::path::Path;
+
+pub enum OptionType {
+    Spin {
+        default: i64,
+        min: i64,
+        max: i64,
+    },
+    Check {
+        default: bool,
+    },
+    Combo {
+        default: &'static str,
+        vars: &'static [&'static str],
+    },
+    Button,
+    String {
+        default: &'static str,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OptionValue {
+    Spin(i64),
+    Check(bool),
+    Combo(&'static str),
+    Button,
+    /// `None` for the empty string.
+    String(Option<String>),
+}
+
+pub struct UciOption {
+    pub name: &'static str,
+    pub option_type: OptionType,
+    apply: fn(&mut Engine, OptionValue) -> Result<(), String>,
+}
+
+// The UCI spec's way of writing an empty string value.
+const EMPTY: &str = "<empty>";
+
+pub static OPTIONS: &[UciOption] = &[
+    UciOption {
+        name: "Hash",
+        option_type: OptionType::Spin {
+            default: 64,
+            min: 1,
+            max: 33_554_432,
+        },
+        apply: |engine, value| {
+            let size = value.spin();
+            engine.set_hash(size as usize).map_err(|_| {
+                format!("cannot allocate a hash table of {size} MB, keeping the old one")
+            })
+        },
+    },
+    UciOption {
+        name: "Clear Hash",
+        option_type: OptionType::Button,
+        apply: |engine, _| {
+            engine.clear_hash();
+            Ok(())
+        },
+    },
+    UciOption {
+        name: "Book",
+        option_type: OptionType::Check { default: true },
+        apply: |engine, value| {
+            engine.set_book(value.check());
+            Ok(())
+        },
+    },
+    UciOption {
+        name: "NN",
+        option_type: OptionType::Check { default: false },
+        apply: |engine, value| {
+            engine.set_nn(value.check());
+            Ok(())
+        },
+    },
+    UciOption {
+        name: "UCI_Chess960",
+        option_type: OptionType::Check { default: false },
+        apply: |engine, value| {
+            engine.set_chess960(value.check());
+            Ok(())
+        },
+    },
+    UciOption {
+        name: "UCI_Variant",
+        option_type: OptionType::Combo {
+            default: "chess",
+            vars: &[
+                "chess",
+                "atomic",
+                "antichess",
+                "kingofthehill",
+                "3check",
+                "crazyhouse",
+                "racingkings",
+                "horde",
+            ],
+        },
+        apply: |engine, value| {
+            let OptionValue::Combo(var) = value else {
+                unreachable!()
+            };
+            engine.set_variant(Variant::from_uci(var).unwrap());
+            Ok(())
+        },
+    },
+    UciOption {
+        name: "Skill Level",
+        option_type: OptionType::Spin {
+            default: skill::MAX_LEVEL as i64,
+            min: 0,
+            max: skill::MAX_LEVEL as i64,
+        },
+        apply: |engine, value| {
+            engine.set_skill_level(value.spin() as u8);
+            Ok(())
+        },
+    },
+    UciOption {
+        name: "UCI_LimitStrength",
+        option_type: OptionType::Check { default: false },
+        apply: |engine, value| {
+            engine.set_limit_strength(value.check());
+            Ok(())
+        },
+    },
+    UciOption {
+        name: "UCI_Elo",
+        option_type: OptionType::Spin {
+            default: skill::MAX_ELO as i64,
+            min: skill::MIN_ELO as i64,
+            max: skill::MAX_ELO as i64,
+        },
+        apply: |engine, value| {
+            engine.set_elo(value.spin() as u32);
+            Ok(())
+        },
+    },
+    UciOption {
+        name: "BookFile",
+        option_type: OptionType::String { default: EMPTY },
+        apply: |engine, value| {
+            let path = value.string();
+            engine
+                .set_book_file(path.as_deref().map(Path::new))
+                .map_err(|err| err.to_string())
+        },
+    },
+    UciOption {
+        name: "BookSelection",
+        option_type: OptionType::Combo {
+            default: "weighted",
+            vars: &["best", "weighted", "uniform"],
+        },
+        apply: |engine, value| {
+            let OptionValue::Combo(var) = value else {
+                unreachable!()
+            };
+            engine.set_book_selection(var.parse().unwrap());
+            Ok(())
+        },
+    },
+    UciOption {
+        name: "BookDepth",
+        option_type: OptionType::Spin {
+            default: 20,
+            min: 0,
+            max: 1000,
+        },
+        apply: |engine, value| {
+            engine.set_book_depth(value.spin() as u32);
+            Ok(())
+        },
+    },
+    UciOption {
+        name: "BookVariety",
+        option_type: OptionType::Spin {
+            default: 100,
+            min: 0,
+            max: 100,
+        },
+        apply: |engine, value| {
+            engine.set_book_variety(value.spin() as u8);
+            Ok(())
+        },
+    },
+    UciOption {
+        name: "BookLearningFile",
+        option_type: OptionType::String { default: EMPTY },
+        apply: |engine, value| {
+            let path = value.string();
+            engine
+                .set_learning_file(path.as_deref().map(Path::new))
+                .map_err(|err| err.to_string())
+        },
+    },
+];
+
+impl OptionValue {
+    // Only called by `apply` functions on values checked by `UciOption::parse`.
+    fn spin(self) -> i64 {
+        match self {
+            OptionValue::Spin(x) => x,
+            _ => unreachable!(),
+        }
+    }
+
+    fn check(self) -> bool {
+        match self {
+            OptionValue::Check(x) => x,
+            _ => unreachable!(),
+        }
+    }
+
+    fn string(self) -> Option<String> {
+        match self {
+            OptionValue::String(x) => x,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl UciOption {
+    /// Option names are not case sensitive.
+    pub fn find(name: &str) -> Option<&'static UciOption> {
+        OPTIONS.iter().find(|o| o.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The `option` line for the `uci` command.
+    pub fn to_uci(&self) -> String {
+        let name = self.name;
+        match &self.option_type {
+            OptionType::Spin { default, min, max } => {
+                format!("option name {name} type spin default {default} min {min} max {max}")
+            }
+            OptionType::Check { default } => {
+                format!("option name {name} type check default {default}")
+            }
+            OptionType::Combo { default, vars } => {
+                let vars: Vec<String> = vars.iter().map(|v| format!("var {v}")).collect();
+                format!(
+                    "option name {name} type combo default {default} {}",
+                    vars.join(" ")
+                )
+            }
+            OptionType::Button => format!("option name {name} type button"),
+            OptionType::String { default } => {
+                format!("option name {name} type string default {default}")
+            }
+        }
+    }
+
+    pub fn default_value(&self) -> Option<OptionValue> {
+        let default = match &self.option_type {
+            OptionType::Spin { default, .. } => default.to_string(),
+            OptionType::Check { default } => default.to_string(),
+            OptionType::Combo { default, .. } => default.to_string(),
+            OptionType::Button => return None,
+            OptionType::String { default } => default.to_string(),
+        };
+        Some(self.parse(Some(&default)).unwrap())
+    }
+
+    /// Checks the value given to `setoption`.
+    pub fn parse(&self, value: Option<&str>) -> Result<OptionValue, String> {
+        let name = self.name;
+        let value = value.map(str::trim);
+
+        match &self.option_type {
+            OptionType::Button => return Ok(OptionValue::Button),
+            OptionType::String { .. } => {
+                let value = value.filter(|v| !v.is_empty() && *v != EMPTY);
+                return Ok(OptionValue::String(value.map(str::to_string)));
+            }
+            _ => (),
+        }
+
+        let Some(value) = value else {
+            return Err(format!("missing value for option {name}"));
+        };
+        let invalid = || format!("invalid value {value} for option {name}");
+
+        match &self.option_type {
+            OptionType::Spin { min, max, .. } => {
+                let x: i64 = value.parse().map_err(|_| invalid())?;
+                if !(*min..=*max).contains(&x) {
+                    return Err(format!(
+                        "value {x} for option {name} is outside {min}..{max}"
+                    ));
+                }
+                Ok(OptionValue::Spin(x))
+            }
+            OptionType::Check { .. } if value.eq_ignore_ascii_case("true") => {
+                Ok(OptionValue::Check(true))
+            }
+            OptionType::Check { .. } if value.eq_ignore_ascii_case("false") => {
+                Ok(OptionValue::Check(false))
+            }
+            OptionType::Combo { vars, .. } => vars
+                .iter()
+                .find(|v| v.eq_ignore_ascii_case(value))
+                .map(|v| OptionValue::Combo(v))
+                .ok_or_else(invalid),
+            _ => Err(invalid()),
+        }
+    }
+
+    pub fn apply(&self, engine: &mut Engine, value: OptionValue) -> Result<(), String> {
+        (self.apply)(engine, value)
+    }
+}
+
+/// Sets every option with a default to that default.
+pub fn apply_defaults(engine: &mut Engine) {
+    for option in OPTIONS {
+        if let Some(value) = option.default_value() {
+            if let Err(err) = option.apply(engine, value) {
+                println!("info string {err}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uci_listing() {
+        let lines: Vec<String> = OPTIONS.iter().map(UciOption::to_uci).collect();
+
+        assert!(lines.contains(&"option name Hash type spin default 64 min 1 max 33554432".into()));
+        assert!(lines.contains(&"option name NN type check default false".into()));
+        assert!(lines.contains(&"option name Clear Hash type button".into()));
+        assert!(lines.contains(
+            &"option name BookSelection type combo default weighted \
+              var best var weighted var uniform"
+                .into()
+        ));
+        assert!(lines.contains(&"option name BookFile type string default <empty>".into()));
+
+        let variant = UciOption::find("UCI_Variant").unwrap();
+        let OptionType::Combo { vars, .. } = variant.option_type else {
+            panic!("UCI_Variant is not a combo");
+        };
+        let variants: Vec<&str> = Variant::ALL.iter().map(|v| v.uci()).collect();
+        assert_eq!(vars, variants);
+
+        // Every default must be accepted by its own option.
+        for option in OPTIONS {
+            option.default_value();
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let hash = UciOption::find("hash").unwrap();
+        assert_eq!(hash.parse(Some("128")), Ok(OptionValue::Spin(128)));
+        assert!(hash.parse(Some("0")).is_err());
+        assert!(hash.parse(Some("lots")).is_err());
+        assert!(hash.parse(None).is_err());
+
+        let nn = UciOption::find("NN").unwrap();
+        assert_eq!(nn.parse(Some("True")), Ok(OptionValue::Check(true)));
+        assert!(nn.parse(Some("yes")).is_err());
+
+        let selection = UciOption::find("BookSelection").unwrap();
+        assert_eq!(
+            selection.parse(Some("Best")),
+            Ok(OptionValue::Combo("best"))
+        );
+        assert!(selection.parse(Some("worst")).is_err());
+
+        let file = UciOption::find("BookFile").unwrap();
+        assert_eq!(
+            file.parse(Some("/books/my book.bin")),
+            Ok(OptionValue::String(Some("/books/my book.bin".into())))
+        );
+        assert_eq!(file.parse(Some("<empty>")), Ok(OptionValue::String(None)));
+        assert_eq!(file.parse(None), Ok(OptionValue::String(None)));
+
+        assert!(UciOption::find("Clear Hash").is_some());
+        assert!(UciOption::find("Threads").is_none());
+    }
+
+    #[test]
+    fn test_hash_allocation_failure() {
+        let mut engine = Engine::new();
+        let hash = UciOption::find("Hash").unwrap();
+        let err = hash.apply(&mut engine, OptionValue::Spin(i64::MAX));
+        assert!(err.unwrap_err().contains("keeping the old one"));
+        assert_eq!(hash.apply(&mut engine, OptionValue::Spin(1)), Ok(()));
+    }
+}
@@ -1,169 +1,75 @@
 // UCI Implementation from: https://wbec-ridderkerk.nl/html/UCIProtocol.html
 // Engine also has some UCI output that is not handled through this module
 
-use crate::search;
-use core::panic;
-use shakmaty::{fen::Fen, uci::Uci, Chess, Color, Move, Outcome, Position};
+use crate::game::Game;
+use crate::perft;
+use crate::ucicommand::{GoParams, UciCommand, UciError};
+use crate::ucioptions::{self, UciOption};
+use shakmaty::variant::VariantPosition;
+use shakmaty::zobrist::{Zobrist64, ZobristHash};
+use shakmaty::{fen::Fen, Color, EnPassantMode, File, Outcome, Position, Rank, Square};
 use std::io::stdin;
-
-const LATENCY_MS: u64 = 100;
-
-#[derive(Debug, PartialEq)]
-enum Token {
-    UCI,
-    IsReady,
-    SetOption,
-    Register,
-    UciNewGame,
-    Position,
-    FEN,
-    StartPos,
-    Moves,
-    Go,
-    SearchMoves,
-    Ponder,
-    WTime,
-    BTime,
-    WInc,
-    BInc,
-    MovesToGo,
-    Depth,
-    Nodes,
-    Mate,
-    MoveTime,
-    Infinite,
-    Stop,
-    PonderHit, //unimplemented
-    Quit,
-    Move(String),
-    Number(u64),
-    FENStr(String),
-    OptionName(String),
-    OptionValue(String),
-}
+use std::time::Instant;
 
 pub struct UciProtocol {
-    chess_engine: search::Engine,
-    position: Chess,
-    n_moves: u16,
+    game: Game,
 }
 
 impl UciProtocol {
     pub fn new() -> UciProtocol {
-        UciProtocol {
-            chess_engine: search::Engine::new(),
-            position: Chess::new(),
-            n_moves: 0,
-        }
+        UciProtocol { game: Game::new() }
     }
 
     pub fn demo(&mut self) {
         for _ in 0..50 {
-            let (m, uci, _) = self
-                .chess_engine
-                .find_best_move(&self.position.clone(), 10_000, 6);
-            self.position = self.position.clone().play(&m).unwrap();
+            let (uci, _) = self.game.think(&GoParams {
+                movetime: Some(10_000),
+                depth: Some(6),
+                ..GoParams::default()
+            });
             println!("bestmove {}", uci);
         }
     }
 
-    fn new_game(&mut self) {
-        self.n_moves = 0;
-        self.chess_engine.new_game();
-    }
-
-    fn excecute_command(&mut self, tokens: &Vec<Token>) {
-        for token in tokens {
-            match token {
-                Token::UCI => {
-                    println!("id name minikalle");
-                    println!("id author Eelis Holmstén");
-                    println!("option name Hash type spin default 64 min 1 max 33554432");
-                    println!("option name Book type check default true");
-                    println!("option name NN type check default true");
-                    println!("uciok");
-                }
-                Token::IsReady => println!("readyok"),
-                Token::UciNewGame => self.new_game(),
-                Token::FENStr(fenstr) => {
-                    let fen: Fen = fenstr.parse().unwrap();
-                    if let Ok(position) = fen.into_position(shakmaty::CastlingMode::Standard) {
-                        self.position = position;
-                    } else {
-                        eprintln!("invalid fen");
-                    }
-                }
-                Token::StartPos => {
-                    let position = Chess::new();
-                    self.position = position;
-                }
-                Token::Move(move_string) => {
-                    let uci: Uci = move_string.parse().unwrap();
-
-                    if let Ok(m) = uci.to_move(&self.position) {
-                        self.position = self.position.clone().play(&m).unwrap();
-                    } else {
-                        eprintln!("UCI error");
-                    }
+    fn execute(&mut self, command: UciCommand) -> Result<(), UciError> {
+        match command {
+            UciCommand::Uci => {
+                println!("id name minikalle");
+                println!("id author Eelis Holmstén");
+                for option in ucioptions::OPTIONS {
+                    println!("{}", option.to_uci());
                 }
-                Token::Go => self.handle_go(tokens),
-                Token::Stop => self.stop_search(),
-                Token::SetOption => self.set_option(tokens),
-                _ => (),
+                println!("uciok");
             }
-        }
-    }
-
-    fn handle_go(&mut self, tokens: &Vec<Token>) {
-        let turn = self.position.turn();
-
-        let mut wtime: u64 = 1000;
-        let mut btime: u64 = 1000;
-        let mut winc: u64 = 1000;
-        let mut binc: u64 = 1000;
-
-        let mut movetime: u64 = 0;
-
-        let mut depth: u64 = 20;
-
-        let mut prev_token = tokens.first().unwrap();
-        for token in &tokens[1..] {
-            match token {
-                Token::Number(n) => match prev_token {
-                    Token::WTime => wtime = *n,
-                    Token::BTime => btime = *n,
-                    Token::WInc => winc = *n,
-                    Token::BInc => binc = *n,
-                    Token::Depth => depth = *n,
-                    Token::MoveTime => movetime = *n,
-                    _ => movetime = 1000,
-                },
-                Token::Infinite => movetime = u64::MAX,
-                _ => prev_token = token,
+            UciCommand::IsReady => println!("readyok"),
+            UciCommand::SetOption { name, value } => self.set_option(&name, value.as_deref())?,
+            UciCommand::UciNewGame => self.game.new_game(),
+            UciCommand::Position { fen, moves } => {
+                self.game.set_position(fen.as_ref(), &moves)?;
+                self.check_game_over();
+            }
+            UciCommand::Go(params) => println!("bestmove {}", self.handle_go(&params)),
+            UciCommand::Perft(depth) => self.perft(depth),
+            UciCommand::Divide(depth) => self.divide(depth),
+            UciCommand::Display => self.display(),
+            UciCommand::Stop => self.stop_search(),
+            UciCommand::Debug | UciCommand::Register | UciCommand::PonderHit | UciCommand::Quit => {
             }
         }
+        Ok(())
+    }
 
-        let max_depth = depth as u8;
-        let max_time = match turn {
-            Color::White => wtime / 20 + winc,
-            Color::Black => btime / 20 + binc,
-        } - LATENCY_MS;
-
-        let chess_move: Move;
-        let uci: Uci;
-
-        if movetime != 0 {
-            (chess_move, uci, _) =
-                self.chess_engine
-                    .find_best_move(&self.position.clone(), movetime, max_depth);
-        } else {
-            (chess_move, uci, _) =
-                self.chess_engine
-                    .find_best_move(&self.position.clone(), max_time, max_depth);
+    /// Lets the engine learn from its book moves once the game has ended.
+    fn check_game_over(&mut self) {
+        if let Err(err) = self.game.check_game_over() {
+            println!("info string cannot save learning file: {err}");
         }
+    }
 
-        self.n_moves += 1;
-        if let Some(outcome) = self.position.outcome() {
+    /// Searches and plays the best move. Returns the move in UCI notation, or
+    /// the null move `0000` if the game is already over.
+    fn handle_go(&mut self, params: &GoParams) -> String {
+        if let Some(outcome) = self.game.position().outcome() {
             match outcome {
                 Outcome::Draw => println!("info outcome 1/2-1/2"),
                 Outcome::Decisive { winner } => match winner {
@@ -171,163 +77,380 @@ impl UciProtocol {
                     Color::Black => println!("info outcome 0-1"),
                 },
             }
-        } else {
-            self.position = self.position.clone().play(&chess_move).unwrap();
-            println!("bestmove {}", uci);
+            return "0000".to_string();
         }
+
+        let (uci, _) = self.game.think(params);
+        self.check_game_over();
+        uci.to_string()
+    }
+
+    fn perft(&self, depth: u32) {
+        let start_time = Instant::now();
+        let nodes = perft::perft(self.game.position(), depth);
+        let time = start_time.elapsed().as_millis() as u64;
+        let nps = nodes * 1000 / (time + 1);
+        println!("info depth {depth} nodes {nodes} time {time} nps {nps}");
+    }
+
+    fn divide(&self, depth: u32) {
+        let castling_mode = self.game.engine.castling_mode();
+        let mut total = 0;
+        for (m, nodes) in perft::divide(self.game.position(), depth) {
+            println!("{}: {nodes}", m.to_uci(castling_mode));
+            total += nodes;
+        }
+        println!("Nodes searched: {total}");
+    }
+
+    fn display(&self) {
+        let position = self.game.position();
+        let board = position.board();
+        println!();
+        println!(" +---+---+---+---+---+---+---+---+");
+        for rank in Rank::ALL.into_iter().rev() {
+            let pieces: Vec<String> = File::ALL
+                .into_iter()
+                .map(
+                    |file| match board.piece_at(Square::from_coords(file, rank)) {
+                        Some(piece) => format!(" {} |", piece.char()),
+                        None => "   |".to_string(),
+                    },
+                )
+                .collect();
+            println!(" |{} {}", pieces.concat(), rank.char());
+            println!(" +---+---+---+---+---+---+---+---+");
+        }
+        println!("   a   b   c   d   e   f   g   h");
+        println!();
+
+        let fen = Fen::from_position(position.clone(), EnPassantMode::Legal).to_string();
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        let key = position.zobrist_hash::<Zobrist64>(EnPassantMode::Legal).0;
+        let checkers: Vec<String> = position
+            .checkers()
+            .into_iter()
+            .map(|sq| sq.to_string())
+            .collect();
+        let in_book = match position {
+            VariantPosition::Chess(chess) => self.game.engine.in_book(chess),
+            _ => false,
+        };
+
+        println!("Fen: {fen}");
+        println!("Key: {key:016X}");
+        println!("Side to move: {}", position.turn());
+        println!("Castling: {}", fields[2]);
+        println!("En passant: {}", fields[3]);
+        println!("Checkers: {}", checkers.join(" "));
+        println!("Book: {}", if in_book { "yes" } else { "no" });
+        println!(
+            "Static eval: {} (side to move)",
+            self.game.engine.static_eval(position)
+        );
     }
 
     fn stop_search(&mut self) {}
 
-    fn set_option(&mut self, tokens: &Vec<Token>) {
-        // TODO: de-jank, add more options.
-        // Should probably use a map or something.
-
-        match tokens.get(1).unwrap() {
-            Token::OptionName(x) => match x.as_str() {
-                "Hash" => {
-                    let value: usize = match tokens.last().unwrap() {
-                        Token::OptionValue(x) => x.parse().unwrap(),
-                        _ => panic!(),
-                    };
-                    self.chess_engine.set_hash(value);
-                }
-                "Book" => {
-                    let value: bool = match tokens.last().unwrap() {
-                        Token::OptionValue(x) => match x.as_str() {
-                            "true" => true,
-                            "false" => false,
-                            _ => true,
-                        },
-                        _ => true,
-                    };
-                    self.chess_engine.set_book(value);
-                }
-                "NN" => {
-                    let value: bool = match tokens.last().unwrap() {
-                        Token::OptionValue(x) => match x.as_str() {
-                            "true" => true,
-                            "false" => false,
-                            _ => true,
-                        },
-                        _ => true,
-                    };
-                    self.chess_engine.set_nn(value);
+    fn set_option(&mut self, name: &str, value: Option<&str>) -> Result<(), UciError> {
+        let option =
+            UciOption::find(name).ok_or_else(|| UciError::UnknownOption(name.to_string()))?;
+        option
+            .parse(value)
+            .and_then(|value| option.apply(&mut self.game.engine, value))
+            .map_err(UciError::InvalidOption)
+    }
+
+    /// Parses and executes one line of input. Returns false on `quit`.
+    fn handle_line(&mut self, line: &str) -> bool {
+        match UciCommand::parse(line) {
+            Ok(UciCommand::Quit) => return false,
+            Ok(command) => {
+                if let Err(err) = self.execute(command) {
+                    println!("{}", info_string(&err));
                 }
-                _ => eprintln!("unkown option {x:?}"),
-            },
-            _ => eprintln!("parser error {tokens:?}"),
+            }
+            Err(UciError::Empty) => (),
+            Err(err) => println!("{}", info_string(&err)),
         }
+        true
     }
 
-    fn parse_message(&mut self, message: &String) -> Vec<Token> {
-        let mut split_message = message.split_whitespace();
+    pub fn put(&mut self, message: &String) {
+        println!("{message}");
+        self.handle_line(message);
+    }
 
-        let mut tokens = vec![];
-        let mut fen_buffer = String::new();
-        let mut is_fen = false;
+    pub fn start(&mut self) {
+        eprintln!("minikalle by Eelis Holmstén");
 
-        loop {
-            let Some(symbol) = split_message.next() else {
+        for line in stdin().lines() {
+            let Ok(line) = line else {
                 break;
             };
-            match symbol {
-                "uci" => tokens.push(Token::UCI),
-                "isready" => tokens.push(Token::IsReady),
-                "register" => tokens.push(Token::Register),
-                "ucinewgame" => tokens.push(Token::UciNewGame),
-                "position" => tokens.push(Token::Position),
-                "fen" => {
-                    tokens.push(Token::FEN);
-                    is_fen = true;
-                    fen_buffer.clear();
-                }
-                "startpos" => tokens.push(Token::StartPos),
-                "moves" => tokens.push(Token::Moves),
-                "go" => tokens.push(Token::Go),
-                "searchmoves" => tokens.push(Token::SearchMoves),
-                "ponder" => tokens.push(Token::Ponder),
-                "wtime" => tokens.push(Token::WTime),
-                "btime" => tokens.push(Token::BTime),
-                "winc" => tokens.push(Token::WInc),
-                "binc" => tokens.push(Token::BInc),
-                "movestogo" => tokens.push(Token::MovesToGo),
-                "depth" => tokens.push(Token::Depth),
-                "nodes" => tokens.push(Token::Nodes),
-                "mate" => tokens.push(Token::Mate),
-                "movetime" => tokens.push(Token::MoveTime),
-                "infinite" => tokens.push(Token::Infinite),
-                "stop" => tokens.push(Token::Stop),
-                "ponderhit" => tokens.push(Token::PonderHit),
-                "quit" => tokens.push(Token::Quit),
-                "setoption" => {
-                    tokens.push(Token::SetOption);
-                    assert_eq!(split_message.next().unwrap(), "name");
-                    tokens.push(Token::OptionName(split_message.next().unwrap().to_string()));
-                    assert_eq!(split_message.next().unwrap(), "value");
-                    tokens.push(Token::OptionValue(
-                        split_message.next().unwrap().to_string(),
-                    ));
-                }
-                _ => {
-                    if is_fen {
-                        fen_buffer.push(' '); //trailing space to account for split method stripping whitespace
-                        fen_buffer.push_str(symbol);
-
-                        if self.is_fen_string(&fen_buffer[1..]) {
-                            tokens.push(Token::FENStr(fen_buffer.clone()));
-                            fen_buffer.clear();
-                            is_fen = false;
-                        }
-                    } else if self.is_number(symbol) {
-                        if let Ok(num) = symbol.parse::<u64>() {
-                            tokens.push(Token::Number(num));
-                        }
-                    } else if self.is_move(symbol) {
-                        tokens.push(Token::Move(symbol.to_string()));
-                    }
-                }
+            if !self.handle_line(&line) {
+                break;
             }
         }
+    }
+}
 
-        if is_fen {
-            eprintln!("unreconized fen string {message}");
-        }
+/// The error as a single line for the GUI to show to the user.
+fn info_string(err: &UciError) -> String {
+    format!("info string {err}")
+}
 
-        tokens
+impl Default for UciProtocol {
+    fn default() -> UciProtocol {
+        UciProtocol::new()
     }
+}
 
-    fn is_fen_string(&mut self, symbol: &str) -> bool {
-        symbol.parse::<Fen>().is_ok()
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::move_time;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+    use shakmaty::variant::Variant;
+
+    // Options that allocate memory or write files are left out.
+    const WORDS: &[&str] = &[
+        "uci",
+        "UCI_Variant",
+        "crazyhouse",
+        "antichess",
+        "horde",
+        "N@f3",
+        "debug",
+        "on",
+        "isready",
+        "setoption",
+        "name",
+        "value",
+        "Book",
+        "NN",
+        "BookDepth",
+        "BookSelection",
+        "BookFile",
+        "true",
+        "best",
+        "<empty>",
+        "ucinewgame",
+        "position",
+        "startpos",
+        "fen",
+        "moves",
+        "e2e4",
+        "e7e5",
+        "g1f3",
+        "e1g1",
+        "a7a8q",
+        "0000",
+        "go",
+        "wtime",
+        "btime",
+        "winc",
+        "binc",
+        "movestogo",
+        "depth",
+        "nodes",
+        "movetime",
+        "infinite",
+        "searchmoves",
+        "ponder",
+        "mate",
+        "stop",
+        "d",
+        "ponderhit",
+        "register",
+        "quit",
+        "0",
+        "1",
+        "-1",
+        "99",
+        "18446744073709551615",
+        "18446744073709551616",
+        "w",
+        "b",
+        "KQkq",
+        "-",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR",
+        "4k3/8/8/8/8/8/8/4K3",
+        "8/8/8/8/8/8/8/8",
+        "7k/5Q2/6K1/8/8/8/8/8",
+        "kk6/8/8/8/8/8/8/8",
+        "é",
+        "\0",
+    ];
+
+    fn random_line(rng: &mut StdRng) -> String {
+        if rng.gen_bool(0.2) {
+            let len = rng.gen_range(0..40);
+            return (0..len).map(|_| rng.gen::<char>()).collect();
+        }
+        let len = rng.gen_range(0..12);
+        let words: Vec<&str> = (0..len).map(|_| *WORDS.choose(rng).unwrap()).collect();
+        let separator = *[" ", "  ", "\t"].choose(rng).unwrap();
+        words.join(separator)
     }
 
-    fn is_number(&mut self, symbol: &str) -> bool {
-        symbol.parse::<u64>().is_ok()
-    }
+    #[test]
+    fn test_random_input() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut uci = UciProtocol::new();
+        // Every search clears the hash table.
+        uci.handle_line("setoption name Hash value 1");
+
+        for _ in 0..5000 {
+            let line = random_line(&mut rng);
+            let command = UciCommand::parse(&line).map(|command| match command {
+                UciCommand::Go(params) => {
+                    move_time(&params, Color::White);
+                    move_time(&params, Color::Black);
+                    // Keep the searches short.
+                    UciCommand::Go(GoParams {
+                        depth: Some(1),
+                        infinite: false,
+                        movetime: None,
+                        ..params
+                    })
+                }
+                command => command,
+            });
+            match command.and_then(|command| uci.execute(command)) {
+                Ok(()) | Err(UciError::Empty) => (),
+                // Errors are reported on one line, whatever the input.
+                Err(err) => assert_eq!(info_string(&err).lines().count(), 1, "{line:?}"),
+            }
+        }
 
-    fn is_move(&mut self, symbol: &str) -> bool {
-        symbol.parse::<Uci>().is_ok()
+        for line in [
+            "hello",
+            "position",
+            "position fen 8/8/8 w - - 0 1",
+            "position startpos moves e2e5",
+            "position startpos moves e2",
+            "go depth x",
+            "go movetime",
+            "setoption name Threads value 4",
+            "setoption name Hash value lots",
+            "setoption name NN value maybe",
+        ] {
+            let err = UciCommand::parse(line)
+                .and_then(|command| uci.execute(command))
+                .unwrap_err();
+            assert!(info_string(&err).starts_with("info string "), "{line}");
+            assert!(uci.handle_line(line));
+        }
     }
 
-    pub fn put(&mut self, message: &String) {
-        println!("{message}");
-        let tokens = self.parse_message(message);
-        self.excecute_command(&tokens);
+    #[test]
+    fn test_go_game_over() {
+        let mut uci = UciProtocol::new();
+        let params = GoParams {
+            depth: Some(1),
+            ..GoParams::default()
+        };
+
+        // Stalemate and checkmate.
+        for fen in [
+            "7k/5Q2/6K1/8/8/8/8/8 b - - 0 1",
+            "7k/6Q1/6K1/8/8/8/8/8 b - - 0 1",
+        ] {
+            uci.handle_line(&format!("position fen {fen}"));
+            assert_eq!(uci.handle_go(&params), "0000");
+        }
+
+        uci.handle_line("position startpos");
+        assert_ne!(uci.handle_go(&params), "0000");
     }
 
-    pub fn start(&mut self) {
-        eprintln!("minikalle by Eelis Holmstén");
-        let mut message = String::new();
+    #[test]
+    fn test_illegal_position() {
+        let mut uci = UciProtocol::new();
+        uci.handle_line("position startpos moves e2e4");
+        let position = uci.game.position().clone();
+
+        for line in [
+            "position startpos moves e2e4 e2e4",
+            "position fen 8/8/8/8/8/8/8/8 w - - 0 1",
+        ] {
+            let command = UciCommand::parse(line).unwrap();
+            assert!(uci.execute(command).is_err(), "{line}");
+            assert_eq!(uci.game.position(), &position);
+        }
 
-        while message != "quit" {
-            message = String::new();
-            stdin()
-                .read_line(&mut message)
-                .expect("Did not enter a correct string");
-            message = message.trim().to_string();
+        assert!(uci.handle_line("go wtime 10 winc 0 depth 1"));
+        assert!(!uci.handle_line("quit"));
+    }
 
-            let tokens = self.parse_message(&message);
-            self.excecute_command(&tokens);
-        }
+    #[test]
+    fn test_chess960() {
+        let shredder = "rk2r3/pppppppp/8/8/8/8/PPPPPPPP/RK2R3 w EAea - 0 1";
+        let xfen = "rk2r3/pppppppp/8/8/8/8/PPPPPPPP/RK2R3 w KQkq - 0 1";
+        let fen = |line: &str| -> Fen { line.parse().unwrap() };
+
+        let mut uci = UciProtocol::new();
+        assert!(uci.game.set_position(Some(&fen(shredder)), &[]).is_err());
+
+        uci.handle_line("setoption name UCI_Chess960 value true");
+        uci.game.set_position(Some(&fen(shredder)), &[]).unwrap();
+        let position = uci.game.position().clone();
+        uci.game.set_position(Some(&fen(xfen)), &[]).unwrap();
+        assert_eq!(uci.game.position(), &position);
+
+        // Castling is written as the king capturing its own rook.
+        uci.handle_line(&format!("position fen {shredder} moves b1a1 b8e8"));
+        assert_eq!(
+            Fen::from_position(uci.game.position().clone(), EnPassantMode::Legal).to_string(),
+            "r4rk1/pppppppp/8/8/8/8/PPPPPPPP/2KRR3 w - - 2 2"
+        );
+
+        // The standard start position is a Chess960 position too.
+        let moves = "position startpos moves e2e4 e7e5 g1f3 b8c6 f1c4 g8f6";
+        uci.handle_line(&format!("{moves} e1h1"));
+        assert_eq!(
+            uci.game.position().board().king_of(Color::White),
+            Some(Square::G1)
+        );
+
+        uci.handle_line("setoption name UCI_Chess960 value false");
+        uci.handle_line(&format!("{moves} e1g1"));
+        assert_eq!(
+            uci.game.position().board().king_of(Color::White),
+            Some(Square::G1)
+        );
+        assert!(uci.game.set_position(Some(&fen(shredder)), &[]).is_err());
+    }
+
+    #[test]
+    fn test_variant() {
+        let mut uci = UciProtocol::new();
+        uci.handle_line("setoption name UCI_Variant value crazyhouse");
+        uci.handle_line("position startpos moves e2e4 d7d5 e4d5 d8d5 b1c3 d5a5 P@d5");
+        assert_eq!(uci.game.position().variant(), Variant::Crazyhouse);
+        assert_eq!(
+            uci.game.position().board().piece_at(Square::D5),
+            Some(Color::White.pawn())
+        );
+
+        // White has no king in Horde.
+        let fen = "rnbqkbnr/pppppppp/8/1PP2PP1/PPPPPPPP/PPPPPPPP/PPPPPPPP/PPPPPPPP w kq - 0 1";
+        let command = UciCommand::parse(&format!("position fen {fen}")).unwrap();
+        assert!(uci.execute(command.clone()).is_err());
+        uci.handle_line("setoption name UCI_Variant value horde");
+        uci.execute(command).unwrap();
+
+        let fen = "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 3+2 0 2";
+        let command = UciCommand::parse(&format!("position fen {fen}")).unwrap();
+        assert!(uci.execute(command.clone()).is_err());
+        uci.handle_line("setoption name UCI_Variant value 3check");
+        uci.execute(command).unwrap();
+        assert_eq!(
+            Fen::from_position(uci.game.position().clone(), EnPassantMode::Legal).to_string(),
+            fen
+        );
     }
 }
@@ -0,0 +1,559 @@
+// XBoard/CECP v2 implementation from: https://www.gnu.org/software/xboard/engine-intf.html
+//
+// Moves are searched on the input thread, so `?` is ignored. In analysis mode
+// the position is searched on its own thread until a command changes the
+// position or ends the analysis.
+
+use crate::game::Game;
+use crate::search::{Reporter, MAX_DEPTH};
+use crate::searchinfo::{Bound, Score, SearchInfo};
+use crate::ucicommand::{GoParams, UciError};
+use shakmaty::variant::Variant;
+use shakmaty::{fen::Fen, uci::Uci, Color, Outcome, Position};
+use std::fmt;
+use std::io::stdin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+
+// Names of the variants in CECP and the `feature variants` list.
+const VARIANTS: &[(&str, Variant)] = &[
+    ("normal", Variant::Chess),
+    ("atomic", Variant::Atomic),
+    ("giveaway", Variant::Antichess),
+    ("kingofthehill", Variant::KingOfTheHill),
+    ("3check", Variant::ThreeCheck),
+    ("crazyhouse", Variant::Crazyhouse),
+    ("racingkings", Variant::RacingKings),
+    ("horde", Variant::Horde),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum XboardError {
+    UnknownCommand(String),
+    InvalidArgument(String),
+    IllegalMove(String),
+    IllegalPosition(String),
+}
+
+impl fmt::Display for XboardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            XboardError::UnknownCommand(command) => write!(f, "Error (unknown command): {command}"),
+            XboardError::InvalidArgument(command) => {
+                write!(f, "Error (invalid argument): {command}")
+            }
+            XboardError::IllegalMove(m) => write!(f, "Illegal move: {m}"),
+            XboardError::IllegalPosition(fen) => write!(f, "tellusererror Illegal position {fen}"),
+        }
+    }
+}
+
+impl From<UciError> for XboardError {
+    fn from(err: UciError) -> XboardError {
+        match err {
+            UciError::IllegalPosition(fen) => XboardError::IllegalPosition(fen),
+            UciError::IllegalMove(m) | UciError::InvalidMove(m) => XboardError::IllegalMove(m),
+            err => XboardError::InvalidArgument(err.to_string()),
+        }
+    }
+}
+
+/// An analysis running on its own thread, which owns the game until the
+/// analysis is stopped.
+struct Analysis {
+    thread: JoinHandle<Game>,
+    stop: Arc<AtomicBool>,
+}
+
+pub struct XboardProtocol {
+    /// `None` while the game is being analyzed.
+    game: Option<Game>,
+    analysis: Option<Analysis>,
+    /// The side the engine plays, `None` in force mode.
+    engine_color: Option<Color>,
+    /// Moves per time control, 0 for the whole game.
+    moves_per_control: u64,
+    /// Clocks of the engine and its opponent in milliseconds.
+    time: u64,
+    opponent_time: u64,
+    increment: u64,
+    /// Fixed time per move set with `st`.
+    move_time: Option<u64>,
+    depth: Option<u64>,
+    post: bool,
+    analyzing: bool,
+}
+
+impl XboardProtocol {
+    pub fn new() -> XboardProtocol {
+        let mut game = Game::new();
+        game.engine.set_print_info(false);
+        XboardProtocol {
+            game: Some(game),
+            analysis: None,
+            engine_color: Some(Color::Black),
+            moves_per_control: 0,
+            time: 300_000,
+            opponent_time: 300_000,
+            increment: 0,
+            move_time: None,
+            depth: None,
+            post: false,
+            analyzing: false,
+        }
+    }
+
+    /// The game, after stopping the analysis if it is running.
+    fn game(&mut self) -> &mut Game {
+        if let Some(analysis) = self.analysis.take() {
+            analysis.stop.store(true, Ordering::Relaxed);
+            let game = analysis.thread.join().expect("analysis thread panicked");
+            analysis.stop.store(false, Ordering::Relaxed);
+            self.game = Some(game);
+        }
+        self.game.as_mut().expect("the game is not being analyzed")
+    }
+
+    fn new_game(&mut self) {
+        let game = self.game();
+        game.engine.set_variant(Variant::Chess);
+        game.engine.set_chess960(false);
+        game.new_game();
+        self.engine_color = Some(Color::Black);
+        self.depth = None;
+    }
+
+    /// Limits of the next search from the time control.
+    fn go_params(&mut self) -> GoParams {
+        let position = self.game().position();
+        let turn = position.turn();
+        let played = position.fullmoves().get() as u64 - 1;
+        let (wtime, btime) = turn.fold_wb(
+            (self.time, self.opponent_time),
+            (self.opponent_time, self.time),
+        );
+        // Spread the remaining time over the moves left in the time control.
+        let movetime = self.move_time.or(match self.moves_per_control {
+            0 => None,
+            n => Some(self.time / (n - played % n) + self.increment),
+        });
+        GoParams {
+            wtime: Some(wtime),
+            btime: Some(btime),
+            winc: Some(self.increment),
+            binc: Some(self.increment),
+            depth: self.depth,
+            movetime,
+            ..GoParams::default()
+        }
+    }
+
+    /// Thinking output is printed with `post` and in analysis mode.
+    fn update_reporter(&mut self) {
+        let reporter: Option<Reporter> = if self.post || self.analyzing {
+            Some(Box::new(print_thinking))
+        } else {
+            None
+        };
+        self.game().engine.set_reporter(reporter);
+    }
+
+    /// Plays a move if it is the engine's turn, or analyzes the position.
+    fn think(&mut self) {
+        if self.game().position().is_game_over() {
+            return;
+        }
+        self.update_reporter();
+        if self.analyzing {
+            self.analyze();
+            return;
+        }
+        let turn = self.game().position().turn();
+        if self.engine_color != Some(turn) {
+            return;
+        }
+
+        let params = self.go_params();
+        let (uci, _) = self.game().think(&params);
+        println!("move {uci}");
+        self.check_game_over();
+    }
+
+    /// Starts searching the position on the analysis thread, one depth at a
+    /// time with a thinking line for each, until the analysis is stopped.
+    fn analyze(&mut self) {
+        self.game();
+        let mut game = self.game.take().expect("the game is not being analyzed");
+        let stop = game.engine.stop_signal();
+        let params = GoParams {
+            depth: Some(self.depth.unwrap_or(MAX_DEPTH as u64)),
+            infinite: true,
+            ..GoParams::default()
+        };
+
+        // Book moves would end the analysis at once.
+        let book = game.engine.uses_book();
+        game.engine.set_book(false);
+
+        let thread = thread::spawn(move || {
+            game.analyze(&params);
+            game.engine.set_book(book);
+            game
+        });
+        self.analysis = Some(Analysis { thread, stop });
+    }
+
+    /// Claims the result when the game has ended and lets the engine learn
+    /// from it.
+    fn check_game_over(&mut self) {
+        let Some(outcome) = self.game().position().outcome() else {
+            return;
+        };
+        match outcome {
+            Outcome::Draw => println!("1/2-1/2 {{Draw}}"),
+            Outcome::Decisive { winner } => match winner {
+                Color::White => println!("1-0 {{White wins}}"),
+                Color::Black => println!("0-1 {{Black wins}}"),
+            },
+        }
+        self.engine_color = None;
+        if let Err(err) = self.game().check_game_over() {
+            println!("telluser cannot save learning file: {err}");
+        }
+    }
+
+    fn user_move(&mut self, m: &str) -> Result<(), XboardError> {
+        let uci: Uci = m
+            .parse()
+            .map_err(|_| XboardError::IllegalMove(m.to_string()))?;
+        self.game().play(&uci)?;
+        self.check_game_over();
+        self.think();
+        Ok(())
+    }
+
+    fn undo(&mut self, n: usize) {
+        for _ in 0..n {
+            self.game().undo();
+        }
+        if self.analyzing {
+            self.think();
+        }
+    }
+
+    /// Records a result announced by the GUI, which may end the game by
+    /// resignation or on time.
+    fn result(&mut self, result: &str) {
+        self.engine_color = None;
+        let outcome = match result {
+            "1-0" => Outcome::Decisive {
+                winner: Color::White,
+            },
+            "0-1" => Outcome::Decisive {
+                winner: Color::Black,
+            },
+            "1/2-1/2" => Outcome::Draw,
+            _ => return,
+        };
+        if let Err(err) = self.game().engine.game_over(outcome) {
+            println!("telluser cannot save learning file: {err}");
+        }
+    }
+
+    fn set_variant(&mut self, name: &str) -> Result<(), XboardError> {
+        let (chess960, variant) = match name {
+            "fischerandom" => (true, Variant::Chess),
+            _ => VARIANTS
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, variant)| (false, *variant))
+                .ok_or_else(|| XboardError::InvalidArgument(format!("variant {name}")))?,
+        };
+        let game = self.game();
+        game.engine.set_chess960(chess960);
+        game.engine.set_variant(variant);
+        game.new_game();
+        Ok(())
+    }
+
+    fn level(&mut self, args: &[&str]) -> Result<(), XboardError> {
+        let invalid = || XboardError::InvalidArgument(format!("level {}", args.join(" ")));
+        let [moves, base, increment] = args else {
+            return Err(invalid());
+        };
+        // The base time is minutes or minutes:seconds, the increment seconds.
+        let (minutes, seconds) = base.split_once(':').unwrap_or((base, "0"));
+        let minutes: u64 = minutes.parse().map_err(|_| invalid())?;
+        let seconds: u64 = seconds.parse().map_err(|_| invalid())?;
+        let increment: f64 = increment.parse().map_err(|_| invalid())?;
+        if !(0.0..1e9).contains(&increment) {
+            return Err(invalid());
+        }
+
+        self.moves_per_control = moves.parse().map_err(|_| invalid())?;
+        self.time = (minutes * 60 + seconds).saturating_mul(1000);
+        self.opponent_time = self.time;
+        self.increment = (increment * 1000.0) as u64;
+        self.move_time = None;
+        Ok(())
+    }
+
+    fn execute(&mut self, line: &str) -> Result<(), XboardError> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&command, args)) = words.split_first() else {
+            return Ok(());
+        };
+        let number = |i: usize| -> Result<u64, XboardError> {
+            args.get(i)
+                .and_then(|x| x.parse().ok())
+                .ok_or_else(|| XboardError::InvalidArgument(line.to_string()))
+        };
+
+        match command {
+            "xboard" | "accepted" | "rejected" | "random" | "hard" | "easy" | "computer"
+            | "name" | "rating" | "ics" | "white" | "black" | "?" | "." => (),
+            "protover" => {
+                let variants: Vec<&str> = VARIANTS.iter().map(|(name, _)| *name).collect();
+                println!(
+                    "feature myname=\"minikalle\" ping=1 setboard=1 usermove=1 analyze=1 \
+                     sigint=0 sigterm=0 colors=0 variants=\"{},fischerandom\"",
+                    variants.join(",")
+                );
+                println!("feature done=1");
+            }
+            "ping" => println!("pong {}", args.first().unwrap_or(&"")),
+            "new" => self.new_game(),
+            "variant" => self.set_variant(args.first().unwrap_or(&""))?,
+            "force" => self.engine_color = None,
+            "go" => {
+                self.engine_color = Some(self.game().position().turn());
+                self.think();
+            }
+            "playother" => self.engine_color = Some(!self.game().position().turn()),
+            "usermove" => self.user_move(args.first().unwrap_or(&""))?,
+            "level" => self.level(args)?,
+            "st" => self.move_time = Some(number(0)?.saturating_mul(1000)),
+            "sd" => self.depth = Some(number(0)?),
+            // Clocks are in centiseconds.
+            "time" => self.time = number(0)?.saturating_mul(10),
+            "otim" => self.opponent_time = number(0)?.saturating_mul(10),
+            "undo" => self.undo(1),
+            "remove" => self.undo(2),
+            "post" => self.post = true,
+            "nopost" => self.post = false,
+            "analyze" => {
+                self.engine_color = None;
+                self.analyzing = true;
+                self.think();
+            }
+            "exit" => {
+                self.game();
+                self.analyzing = false;
+            }
+            "quit" => {
+                self.game();
+            }
+            "setboard" => {
+                let fen: Fen = args
+                    .join(" ")
+                    .parse()
+                    .map_err(|_| XboardError::IllegalPosition(args.join(" ")))?;
+                self.game().set_position(Some(&fen), &[])?;
+                if self.analyzing {
+                    self.think();
+                }
+            }
+            "result" => self.result(args.first().unwrap_or(&"")),
+            // Protocol version 1 sends moves without `usermove`.
+            m if m.parse::<Uci>().is_ok() => self.user_move(m)?,
+            _ => return Err(XboardError::UnknownCommand(line.to_string())),
+        }
+        Ok(())
+    }
+
+    /// Executes one line of input. Returns false on `quit`.
+    fn handle_line(&mut self, line: &str) -> bool {
+        if let Err(err) = self.execute(line) {
+            println!("{err}");
+        }
+        line.split_whitespace().next() != Some("quit")
+    }
+
+    pub fn start(&mut self) {
+        for line in stdin().lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            if !self.handle_line(&line) {
+                break;
+            }
+        }
+    }
+}
+
+/// Prints a thinking line: depth, score, time in centiseconds, nodes and the
+/// principal variation. Mate in N is scored 100000 + N. Bounds and root move
+/// reports have no CECP equivalent and are left out.
+fn print_thinking(info: &SearchInfo) {
+    let score = match info.score {
+        _ if info.bound != Bound::Exact => return,
+        Some(Score::Cp(cp)) => cp as i32,
+        Some(Score::Mate(moves)) if moves > 0 => 100_000 + moves as i32,
+        Some(Score::Mate(moves)) => -100_000 + moves as i32,
+        None => return,
+    };
+    let pv: Vec<String> = info.pv.iter().map(Uci::to_string).collect();
+    println!(
+        "{} {score} {} {} {}",
+        info.depth,
+        info.time / 10,
+        info.nodes,
+        pv.join(" ")
+    );
+}
+
+impl Default for XboardProtocol {
+    fn default() -> XboardProtocol {
+        XboardProtocol::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shakmaty::variant::VariantPosition;
+    use shakmaty::Square;
+    use std::time::{Duration, Instant};
+
+    fn xboard(lines: &[&str]) -> XboardProtocol {
+        let mut xboard = XboardProtocol::new();
+        xboard.game().engine.set_book(false);
+        for line in lines {
+            xboard.handle_line(line);
+        }
+        xboard
+    }
+
+    #[test]
+    fn test_game() {
+        let mut xboard = xboard(&["xboard", "protover 2", "new", "sd 2", "usermove e2e4"]);
+        // The engine answered as black.
+        assert_eq!(xboard.game().position().turn(), Color::White);
+        assert_eq!(xboard.game().position().fullmoves().get(), 2);
+
+        xboard.handle_line("force");
+        xboard.handle_line("usermove d2d4");
+        assert_eq!(xboard.game().position().turn(), Color::Black);
+
+        xboard.handle_line("undo");
+        assert_eq!(xboard.game().position().turn(), Color::White);
+        xboard.handle_line("remove");
+        assert_eq!(
+            xboard.game().position(),
+            &VariantPosition::new(Variant::Chess)
+        );
+
+        assert_eq!(
+            xboard.execute("usermove e2e5"),
+            Err(XboardError::IllegalMove("e2e5".into()))
+        );
+        assert!(!xboard.handle_line("quit"));
+    }
+
+    #[test]
+    fn test_analyze() {
+        let start = Instant::now();
+        let mut xboard = xboard(&["new", "post", "analyze", "."]);
+        assert!(xboard.analysis.is_some());
+
+        // Moves restart the analysis from the new position.
+        xboard.handle_line("usermove e2e4");
+        assert!(xboard.analysis.is_some());
+        xboard.handle_line("exit");
+        assert!(xboard.analysis.is_none());
+        assert!(!xboard.analyzing);
+        assert_eq!(xboard.game().position().turn(), Color::Black);
+        assert!(start.elapsed().as_secs() < 5);
+    }
+
+    #[test]
+    fn test_analyze_book_position() {
+        let mut xboard = XboardProtocol::new();
+        xboard.handle_line("new");
+        xboard.handle_line("sd 1");
+        xboard.handle_line("analyze");
+        let analysis = xboard.analysis.as_ref().unwrap();
+        while !analysis.thread.is_finished() {
+            thread::sleep(Duration::from_millis(10));
+        }
+        xboard.handle_line("exit");
+        // The start position is searched instead of answered from the book,
+        // which is back in use afterwards.
+        assert!(xboard.game().engine.last_info().depth > 0);
+        assert!(xboard.game().engine.uses_book());
+    }
+
+    #[test]
+    fn test_quit_analysis() {
+        let mut xboard = xboard(&["new", "analyze"]);
+        assert!(!xboard.handle_line("quit"));
+        assert!(xboard.analysis.is_none());
+    }
+
+    #[test]
+    fn test_setboard() {
+        let mut xboard = xboard(&["new", "force", "sd 3"]);
+        xboard.handle_line("setboard 7k/5Q2/6K1/8/8/8/8/8 w - - 0 1");
+        xboard.handle_line("go");
+        // Mate ends the game.
+        assert!(xboard.game().position().is_checkmate());
+        assert_eq!(xboard.engine_color, None);
+
+        assert!(matches!(
+            xboard.execute("setboard 8/8/8/8/8/8/8/8 w - - 0 1"),
+            Err(XboardError::IllegalPosition(_))
+        ));
+        assert!(matches!(
+            xboard.execute("level 40 x 0"),
+            Err(XboardError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            xboard.execute("frobnicate"),
+            Err(XboardError::UnknownCommand(_))
+        ));
+    }
+
+    #[test]
+    fn test_time_control() {
+        let mut xboard = xboard(&["new", "level 40 5 0", "time 6000", "otim 3000"]);
+        xboard.engine_color = Some(Color::White);
+        let params = xboard.go_params();
+        assert_eq!(params.wtime, Some(60_000));
+        assert_eq!(params.btime, Some(30_000));
+        assert_eq!(params.movetime, Some(60_000 / 40));
+
+        xboard.handle_line("level 0 2:30 1.5");
+        assert_eq!(xboard.time, 150_000);
+        assert_eq!(xboard.go_params().winc, Some(1500));
+        assert_eq!(xboard.go_params().movetime, None);
+
+        xboard.handle_line("st 3");
+        assert_eq!(xboard.go_params().movetime, Some(3000));
+    }
+
+    #[test]
+    fn test_variant() {
+        let mut xboard = xboard(&["new", "variant crazyhouse", "force"]);
+        for m in ["e2e4", "d7d5", "e4d5", "d8d5", "b1c3", "d5a5", "P@d5"] {
+            xboard.handle_line(&format!("usermove {m}"));
+        }
+        assert_eq!(
+            xboard.game().position().board().piece_at(Square::D5),
+            Some(Color::White.pawn())
+        );
+
+        xboard.handle_line("new");
+        assert_eq!(xboard.game().position().variant(), Variant::Chess);
+    }
+}